@@ -16,11 +16,14 @@ enum Commands {
     Eval(EvalArgs),
     /// Evaluate polynomial f(x) as product of linear factors
     EvalAsFactors(EvalAsFactorsArgs),
-    ////TODO  Find roots of polynomial f(x)
-    //TOD Roots(RootsArgs),
+    /// Find roots of polynomial f(x)
+    Roots(RootsArgs),
     /// Plot polynomial f(x)
     Plot(PlotArgs),
-    //roots
+    /// Interpolate points with polynomial and plot it
+    Interpolate(InterpolateArgs),
+    /// Fit polynomial to points from file with least squares and plot it
+    Fit(FitArgs),
 }
 
 #[derive(Args)]
@@ -54,6 +57,17 @@ struct EvalAsFactorsArgs {
     verbose: bool,
 }
 
+#[derive(Args)]
+struct RootsArgs {
+    /// Coefficients c0, c1, c2...
+    #[arg(required = true)]
+    coeffs: Vec<f64>,
+
+    /// Verbose output
+    #[arg(short, long, action = ArgAction::SetTrue)]
+    verbose: bool,
+}
+
 #[derive(Args)]
 struct PlotArgs {
     /// Calculate polynomial as product of linear factors
@@ -86,6 +100,31 @@ struct PlotArgs {
     coeffs: Vec<f64>,
 }
 
+#[derive(Args)]
+struct InterpolateArgs {
+    /// Plot file name w/o extention
+    #[arg(short, long)]
+    file_name: String,
+
+    /// Points x0 y0 x1 y1...
+    #[arg(required = true)]
+    points: Vec<f64>,
+}
+
+#[derive(Args)]
+struct FitArgs {
+    /// Degree of the polynomial
+    #[arg(short, long)]
+    degree: usize,
+
+    /// Plot file name w/o extention
+    #[arg(short, long)]
+    file_name: Option<String>,
+
+    /// File with lines `x y` or `x y w`, w=1/sigma^2, `#` starts a comment
+    points_file: String,
+}
+
 fn main() {
     let cli = Cli::parse();
 
@@ -96,11 +135,24 @@ fn main() {
         Commands::EvalAsFactors (args) => {
             eval_as_factors(args.x, args.scale, &args.roots, args.verbose)
         }
+        Commands::Roots (args) => {
+            find_roots(&args.coeffs, args.verbose)
+        }
         Commands::Plot (args) => {
             if let Err(err) = plot(&args.coeffs, args.start, args.end, args) {
                 println!("Error {}", err);
             }
         }
+        Commands::Interpolate (args) => {
+            if let Err(err) = interpolate(&args.points, &args.file_name) {
+                println!("Error {}", err);
+            }
+        }
+        Commands::Fit (args) => {
+            if let Err(err) = fit(args) {
+                println!("Error {}", err);
+            }
+        }
     }
 }
 
@@ -133,6 +185,27 @@ fn eval_as_factors(x: f64, scale: f64, roots: &[f64], verbose: bool) {
     }
 }
 
+// `rustamath-polynomial roots -- 2 -3 3 -3 1`
+fn find_roots(coeffs: &[f64], verbose: bool) {
+    if verbose {
+        println!("{}", Polynomial::new(coeffs.to_vec()));
+    }
+
+    match roots::rpoly::rpoly(coeffs) {
+        Ok(roots) => {
+            for (i, root) in roots.iter().enumerate() {
+                if root.im == 0.0 {
+                    println!("x{} = {}", i, root.re);
+                }
+                else {
+                    println!("x{} = {} {:+}i", i, root.re, root.im);
+                }
+            }
+        }
+        Err(err) => println!("Error {:?}", err),
+    }
+}
+
 fn print_formula(coeffs: &[f64], scale: f64, as_factors: bool) -> String {
     let mut s = String::new();
     if as_factors {
@@ -255,3 +328,126 @@ fn plot(coeffs: &[f64], x_start: f64, x_end: f64, args: &PlotArgs)
 
     Ok(())
 }
+
+// `rustamath-polynomial interpolate -f ../interpolate -- 0 1 1 3 2 2 4 5`
+fn interpolate(points: &[f64], file_name: &str) -> Result<(), Box<dyn std::error::Error>> {
+    if points.len() % 2 == 1 {
+        return Err("points must be pairs of x and y".into());
+    }
+    let xs: Vec<f64> = points.iter().step_by(2).copied().collect();
+    let ys: Vec<f64> = points.iter().skip(1).step_by(2).copied().collect();
+    if xs.iter().enumerate().any(|(i, x)| xs[..i].contains(x)) {
+        return Err("interpolation points must have distinct x".into());
+    }
+
+    let coeffs = interpolation::interpolating_polynomial(&xs, &ys);
+    println!("{}", Polynomial::new(coeffs.clone()));
+
+    let xy: Vec<(f64,f64)> = xs.into_iter().zip(ys).collect();
+    plot_through_points(&coeffs, &xy, file_name)
+}
+
+/// Plot polynomial over the range of the points and mark the points
+fn plot_through_points(coeffs: &[f64], points: &[(f64,f64)], file_name: &str)
+-> Result<(), Box<dyn std::error::Error>>
+{
+    let file_name = String::from(file_name) + ".svg";
+    println!("Saving to file {}", file_name);
+    let backend = SVGBackend::new(
+        &file_name,
+        (800, 800)).into_drawing_area();
+
+    backend.fill(&WHITE)?;
+
+    let x_start = points.iter().fold(f64::INFINITY, |a, (x,_y)| a.min(*x));
+    let x_end = points.iter().fold(f64::NEG_INFINITY, |a, (x,_y)| a.max(*x));
+    let margin = if x_end > x_start { 0.05*(x_end - x_start) } else { 1.0 };
+    let (x_start, x_end) = (x_start - margin, x_end + margin);
+
+    let ps = (0..=200)
+        .map(|x| x_start + (x as f64)*(x_end - x_start)/200.0)
+        .map(|x| (x, polynomial_n(x, coeffs)))
+        .collect::<Vec<(f64,f64)>>();
+    let y_start = ps.iter().chain(points).fold(f64::INFINITY, |a, (_x,y)| a.min(*y));
+    let y_end = ps.iter().chain(points).fold(f64::NEG_INFINITY, |a, (_x,y)| a.max(*y));
+    let margin = if y_end > y_start { 0.05*(y_end - y_start) } else { 1.0 };
+
+    let mut chart = ChartBuilder::on(&backend)
+        .caption(print_formula(coeffs, 1.0, false),
+            ("sans-serif", 20).into_font())
+        .margin(5)
+        .x_label_area_size(30)
+        .y_label_area_size(40)
+        .build_cartesian_2d(x_start..x_end, (y_start - margin)..(y_end + margin))?;
+
+    chart.configure_mesh().draw()?;
+
+    chart
+        .draw_series(LineSeries::new(
+            ps,
+            &RED,
+        ))?
+        .label("y = P(x)")
+        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], RED));
+
+    chart
+        .draw_series(points.iter().map(|p| Circle::new(*p, 4, BLUE.filled())))?
+        .label("points")
+        .legend(|(x, y)| Circle::new((x + 10, y), 4, BLUE.filled()));
+
+    chart
+        .configure_series_labels()
+        .background_style(WHITE.mix(0.8))
+        .border_style(BLACK)
+        .draw()?;
+
+    Ok(())
+}
+
+/// Columns x, y and w of points file, w is `None` if no line has it
+type Points = (Vec<f64>, Vec<f64>, Option<Vec<f64>>);
+
+/// Read points `x y [w]` from the file, weight is 1 if not given
+fn read_points(file_name: &str) -> Result<Points, Box<dyn std::error::Error>> {
+    let text = std::fs::read_to_string(file_name)?;
+    let (mut xs, mut ys, mut ws) = (Vec::new(), Vec::new(), Vec::new());
+    let mut weighted = false;
+
+    for (nr, line) in text.lines().enumerate() {
+        let line = line.split('#').next().unwrap_or("").trim();
+        if line.is_empty() {
+            continue;
+        }
+        let values = line.split(|c: char| c.is_whitespace() || c == ',')
+            .filter(|v| !v.is_empty())
+            .map(|v| v.parse::<f64>())
+            .collect::<Result<Vec<f64>, _>>()
+            .map_err(|err| format!("line {}: {}", nr + 1, err))?;
+        match values[..] {
+            [x, y] => { xs.push(x); ys.push(y); ws.push(1.0); }
+            [x, y, w] => { xs.push(x); ys.push(y); ws.push(w); weighted = true; }
+            _ => return Err(format!("line {}: expected `x y` or `x y w`", nr + 1).into()),
+        }
+    }
+    Ok((xs, ys, if weighted { Some(ws) } else { None }))
+}
+
+// `rustamath-polynomial fit -d 2 -f ../fit points.txt`
+fn fit(args: &FitArgs) -> Result<(), Box<dyn std::error::Error>> {
+    let (xs, ys, ws) = read_points(&args.points_file)?;
+
+    let res = fit::polynomial_fit(&xs, &ys, ws.as_deref(), args.degree)
+        .map_err(|err| format!("{:?}", err))?;
+
+    println!("{}", Polynomial::new(res.coeffs.clone()));
+    for (i, (c, e)) in res.coeffs.iter().zip(res.std_errors()).enumerate() {
+        println!("c{} = {} +/- {}", i, c, e);
+    }
+    println!("chi^2 = {}, dof = {}, R^2 = {}", res.chi_square, res.dof, res.r_squared);
+
+    if let Some(file_name) = &args.file_name {
+        let xy: Vec<(f64,f64)> = xs.into_iter().zip(ys).collect();
+        plot_through_points(&res.coeffs, &xy, file_name)?;
+    }
+    Ok(())
+}
@@ -94,6 +94,36 @@ pub mod complex;
     a.mul_add(b, c)
 }
 
+/// Error-free sum `a + b = s + e` where `s = fl(a + b)`, Knuth's TwoSum
+///
+/// # Example
+/// ```
+/// # use rustamath::function::*;
+/// let (s, e) = two_sum(1.0, 1.0e-20);
+/// assert_eq!((s, e), (1.0, 1.0e-20));
+/// ```
+#[inline] pub fn two_sum<T: Float>(a: T, b: T) -> (T, T) {
+    let s = a + b;
+    let z = s - a;
+    let e = (a - (s - z)) + (b - z);
+    (s, e)
+}
+
+/// Error-free product `a*b = p + e` where `p = fl(a*b)`, TwoProduct with FMA
+///
+/// # Example
+/// ```
+/// # use rustamath::function::*;
+/// let x = 1.0 + f64::EPSILON;
+/// let (p, e) = two_product(x, x);
+/// assert_eq!((p, e), (1.0 + 2.0*f64::EPSILON, f64::EPSILON*f64::EPSILON));
+/// ```
+#[inline] pub fn two_product<T: Float>(a: T, b: T) -> (T, T) {
+    let p = a * b;
+    let e = a.mul_add(b, -p);
+    (p, e)
+}
+
 /// x^2
 #[inline] pub fn pow_2<T: Float>(x: T) -> T {
     x * x
@@ -1,3 +1,4 @@
 //! Linear Algebra
 
-pub mod tnsr;
\ No newline at end of file
+pub mod tnsr;
+pub(crate) mod gauss;
\ No newline at end of file
@@ -0,0 +1,39 @@
+//! Gaussian elimination with partial pivoting.
+//!
+//! (c) Igor Lesik 2023
+//! MIT license
+
+/// Solve row-major `n x n` system `A*x = b` by Gaussian elimination with partial pivoting,
+/// `a` and `b` are overwritten, `None` if the matrix is singular
+pub(crate) fn solve_linear(a: &mut [f64], b: &mut [f64], n: usize) -> Option<Vec<f64>> {
+    let scale = a.iter().fold(0.0f64, |s, x| s.max(x.abs()));
+    let tiny = n as f64 * f64::EPSILON * scale;
+
+    for k in 0..n {
+        let p = (k..n).max_by(|&i, &j| a[i*n + k].abs().total_cmp(&a[j*n + k].abs()))?;
+        if a[p*n + k].abs() <= tiny {
+            return None;
+        }
+        if p != k {
+            for j in 0..n {
+                a.swap(k*n + j, p*n + j);
+            }
+            b.swap(k, p);
+        }
+        for i in k + 1..n {
+            let f = a[i*n + k] / a[k*n + k];
+            for j in k..n {
+                a[i*n + j] -= f * a[k*n + j];
+            }
+            b[i] -= f * b[k];
+        }
+    }
+
+    let mut x = vec![0.0; n];
+    for k in (0..n).rev() {
+        let s = (k + 1..n).fold(b[k], |s, j| s - a[k*n + j] * x[j]);
+        x[k] = s / a[k*n + k];
+    }
+
+    if x.iter().all(|v| v.is_finite()) { Some(x) } else { None }
+}
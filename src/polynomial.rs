@@ -1,6 +1,27 @@
 //! Polynomial functions.
 //!
 
+pub mod poly;
+pub mod division;
+pub mod roots;
+pub mod solve;
+pub mod compensated;
+pub mod interpolation;
+pub mod fit;
+pub mod orthogonal;
+pub mod chebyshev;
+pub mod rational;
+pub mod transform;
+pub mod bernstein;
+
+#[cfg(test)]
+mod test_helpers;
+
+pub use poly::Polynomial;
+pub use solve::{Solutions, discriminant, solve_quadratic, solve_quadratic_complex,
+    solve_cubic, solve_cubic_complex, solve_quartic, solve_quartic_complex};
+pub use compensated::{compensated_polynomial_n, compensated_polynomial_n_bound};
+
 /// Polynomial c0 + c1*x + c2*x^2
 ///
 /// # Example
@@ -69,6 +90,8 @@ pub fn naive_polynomial_n(x: f64, cs: &[f64]) -> f64 {
 /// [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method) uses Horner rule:
 /// `c0 + c1*x + c2*x^2 + c3*x^3 ... = c0 + x(c1 + x(c2 + x(c3 ...`
 ///
+/// Accuracy is lost near multiple roots, use [`compensated_polynomial_n`] there.
+///
 /// # Example
 ///
 /// ```
@@ -150,29 +173,81 @@ pub fn derivative_polynomial_n(x: f64, cs: &[f64]) -> (f64, f64, f64) {
     (x, p, dp)
 }
 
-/* evaluation of the polynomial and nd of its derivatives simultaneously
-Given the coefficients of a polynomial of degree nc as an array c[0..nc] of size nc+1 (with
-c[0] being the constant term), and given a value x, this routine fills an output array pd of size
-nd+1 with the value of the polynomial evaluated at x in pd[0], and the first nd derivatives at
-x in pd[1..nd].
-
-void ddpoly(VecDoub_I &c, const Doub x, VecDoub_O &pd)
-{
-Int nnd,j,i,nc=c.size()-1,nd=pd.size()-1;
-Doub cnst=1.0;
-pd[0]=c[nc];
-for (j=1;j<nd+1;j++) pd[j]=0.0;
-for (i=nc-1;i>=0;i--) {
-nnd=(nd < (nc-i) ? nd : nc-i);
-for (j=nnd;j>0;j--) pd[j]=pd[j]*x+pd[j-1];
-pd[0]=pd[0]*x+c[i];
+/// Polynomial and its first `nd` derivatives evaluated simultaneously, `nd = pd.len() - 1`
+///
+/// Fills `pd[0]` with `P(x)` and `pd[1..]` with derivatives `P'(x), P''(x), ...`,
+/// derivatives of order higher than the degree are zeros.
+/// This is `ddpoly` from Numerical Recipes, Horner's scheme is applied
+/// to the derivatives in one pass and factorial constants are applied at the end.
+///
+/// # Example
+///
+/// ```
+/// # use rustamath::polynomial::*;
+/// // x^3 + 2x^2 - x + 3
+/// let mut pd = [0.0; 5];
+/// derivatives_polynomial_n(2.0, &[3.0, -1.0, 2.0, 1.0], &mut pd);
+/// assert_eq!(pd, [17.0, 19.0, 16.0, 6.0, 0.0]);
+/// ```
+pub fn derivatives_polynomial_n(x: f64, cs: &[f64], pd: &mut [f64]) {
+    let nc = cs.len() - 1;
+    let nd = pd.len() - 1;
+
+    pd[0] = cs[nc];
+    for p in pd.iter_mut().skip(1) {
+        *p = 0.0;
+    }
+
+    for i in (0..nc).rev() {
+        let nnd = nd.min(nc - i);
+        for j in (1..=nnd).rev() {
+            pd[j] = pd[j]*x + pd[j-1];
+        }
+        pd[0] = pd[0]*x + cs[i];
+    }
+
+    // after the first derivative, factorial constants come in
+    let mut cnst = 1.0;
+    for (i, p) in pd.iter_mut().enumerate().skip(2) {
+        cnst *= i as f64;
+        *p *= cnst;
+    }
 }
-for (i=2;i<nd+1;i++) { After the first derivative, factorial constants come in.
-cnst *= i;
-pd[i] *= cnst;
+
+/// Coefficients of the derivative `c1 + 2*c2*x + 3*c3*x^2 + ...`
+///
+/// Derivative of a constant is `[0.0]`.
+///
+/// # Example
+///
+/// ```
+/// # use rustamath::polynomial::*;
+/// assert_eq!(derivative_coeffs(&[5.0, 3.0, 2.0, 1.0]), vec![3.0, 4.0, 3.0]);
+/// assert_eq!(derivative_coeffs(&[5.0]), vec![0.0]);
+/// ```
+pub fn derivative_coeffs(cs: &[f64]) -> Vec<f64> {
+    if cs.len() < 2 {
+        return vec![0.0];
+    }
+    cs.iter().enumerate().skip(1).map(|(i, c)| c * i as f64).collect()
 }
+
+/// Coefficients of the antiderivative `k + c0*x + c1/2*x^2 + c2/3*x^3 + ...`
+///
+/// # Example
+///
+/// ```
+/// # use rustamath::polynomial::*;
+/// let p = [3.0, 4.0, 3.0];
+/// let int = integral_coeffs(&p, 5.0);
+/// assert_eq!(int, vec![5.0, 3.0, 2.0, 1.0]);
+/// assert_eq!(derivative_coeffs(&int), p);
+/// ```
+pub fn integral_coeffs(cs: &[f64], k: f64) -> Vec<f64> {
+    std::iter::once(k)
+        .chain(cs.iter().enumerate().map(|(i, c)| c / (i + 1) as f64))
+        .collect()
 }
-*/
 
 #[cfg(test)]
 mod tests {
@@ -208,4 +283,35 @@ mod tests {
         assert_f64_near!(polynomial_as_product_of_linear_factors(x, 2.0, &roots),
             2.0*(x+1.0)*(x)*(x-1.0));
     }
+
+    #[test]
+    fn derivatives() {
+        let c = [1.1, 2.2, 3.3, 4.4, 5.5, 6.6];
+        let x = 0.12345678;
+        let mut pd = [0.0; 8];
+        derivatives_polynomial_n(x, &c, &mut pd);
+
+        // k-th derivative by differentiating coefficients k times
+        let mut dc = c.to_vec();
+        for (k, p) in pd.iter().enumerate() {
+            assert_f64_near!(*p, polynomial_n(x, &dc), 8);
+            if k == 1 {
+                assert_f64_near!(*p, derivative_polynomial_n(x, &c).2);
+            }
+            dc = derivative_coeffs(&dc);
+        }
+        assert_eq!(&pd[6..], &[0.0, 0.0]);
+
+        // value only
+        let mut pd = [0.0];
+        derivatives_polynomial_n(x, &c, &mut pd);
+        assert_f64_near!(pd[0], polynomial_n(x, &c));
+
+        let int = integral_coeffs(&c, -1.0);
+        assert_eq!(int.len(), c.len() + 1);
+        assert_eq!(polynomial_n(0.0, &int), -1.0);
+        for (a, b) in derivative_coeffs(&int).iter().zip(c) {
+            assert_f64_near!(*a, b);
+        }
+    }
 }
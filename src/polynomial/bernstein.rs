@@ -0,0 +1,315 @@
+//! Polynomial in Bernstein form.
+//!
+//! (c) Igor Lesik 2023
+//! MIT license
+//!
+//! Polynomial of degree `n` on interval `[a, b]` is `sum(b_i * B_i(t))`,
+//! where `t = (x - a)/(b - a)` and `B_i(t) = C(n, i) * t^i * (1 - t)^(n - i)`.
+//! Bernstein basis polynomials are non-negative and sum to one on `[a, b]`, so
+//! values of the polynomial lie between the smallest and largest coefficient,
+//! and the number of coefficient sign changes bounds the number of roots in `(a, b)`
+//! (Descartes' rule of signs). De Casteljau's algorithm evaluates and subdivides
+//! the polynomial with convex combinations only, which is numerically stable.
+//!
+//! References:
+//!
+//! - <https://en.wikipedia.org/wiki/Bernstein_polynomial>
+//! - <https://en.wikipedia.org/wiki/De_Casteljau%27s_algorithm>
+//! - <https://en.wikipedia.org/wiki/Descartes%27_rule_of_signs#Bernstein_basis>
+
+use super::Polynomial;
+
+/// Polynomial in Bernstein form on interval `[a, b]`
+///
+/// # Example
+///
+/// ```
+/// # use rustamath::polynomial::bernstein::Bernstein;
+/// # use assert_float_eq::*;
+/// // (x - 1)(x - 3) on [0, 4]
+/// let p = Bernstein::from_monomial(&[3.0, -4.0, 1.0], 0.0, 4.0);
+/// assert_eq!(p.coeffs(), &[3.0, -5.0, 3.0]);
+/// assert_eq!(p.eval(2.0), -1.0);
+/// assert_eq!(p.sign_changes(), 2);
+///
+/// let roots = p.isolate_roots(1.0e-12);
+/// assert_eq!(roots.len(), 2);
+/// assert!(roots[0].0 < 1.0 && 1.0 < roots[0].1);
+/// assert!(roots[1].0 < 3.0 && 3.0 < roots[1].1);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Bernstein {
+    coeffs: Vec<f64>,
+    a: f64,
+    b: f64,
+}
+
+impl Bernstein {
+    /// Create polynomial from Bernstein coefficients on `[a, b]`
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are no coefficients or if `a >= b`.
+    pub fn new(coeffs: Vec<f64>, a: f64, b: f64) -> Bernstein {
+        assert!(!coeffs.is_empty(), "polynomial must have at least one coefficient");
+        assert!(a < b, "interval must have a < b");
+        Bernstein {coeffs, a, b}
+    }
+
+    /// Convert monomial coefficients `c0, c1, ...` to Bernstein form on `[a, b]`
+    ///
+    /// `b_i = sum(C(i, k)/C(n, k) * p_k, k = 0..i)` where `p_k` are
+    /// coefficients of `P(a + (b - a)*t)`.
+    pub fn from_monomial(cs: &[f64], a: f64, b: f64) -> Bernstein {
+        assert!(!cs.is_empty(), "polynomial must have at least one coefficient");
+        assert!(a < b, "interval must have a < b");
+
+        let n = cs.len() - 1;
+        let mut p = Polynomial::new(cs.to_vec()).shift(a).scale(b - a).into_coeffs();
+        p.resize(n + 1, 0.0);
+
+        let coeffs = (0..=n).map(|i| {
+            // C(i, k)/C(n, k) updated term by term
+            let mut ratio = 1.0;
+            let mut s = p[0];
+            for (k, pk) in p.iter().enumerate().take(i + 1).skip(1) {
+                ratio *= (i + 1 - k) as f64 / (n + 1 - k) as f64;
+                s += ratio * pk;
+            }
+            s
+        }).collect();
+
+        Bernstein {coeffs, a, b}
+    }
+
+    /// Monomial coefficients `c0, c1, ...`
+    ///
+    /// `p_k = C(n, k) * sum((-1)^(k - i) * C(k, i) * b_i, i = 0..k)` in `t`,
+    /// then `t = (x - a)/(b - a)`.
+    pub fn to_monomial(&self) -> Vec<f64> {
+        let n = self.degree();
+        let mut p = vec![0.0; n + 1];
+        let mut cnk = 1.0;
+        for (k, pk) in p.iter_mut().enumerate() {
+            // k-th forward difference of coefficients
+            let mut cki = 1.0;
+            let mut s = 0.0;
+            for i in (0..=k).rev() {
+                s += cki * self.coeffs[i];
+                cki *= -(i as f64) / (k + 1 - i) as f64;
+            }
+            *pk = cnk * s;
+            cnk *= (n - k) as f64 / (k + 1) as f64;
+        }
+        let mut c = Polynomial::new(p).scale(1.0 / (self.b - self.a)).shift(-self.a).into_coeffs();
+        c.resize(n + 1, 0.0);
+        c
+    }
+
+    /// Bernstein coefficients `b0, b1, ...`
+    pub fn coeffs(&self) -> &[f64] {
+        &self.coeffs
+    }
+
+    /// Degree of the polynomial, number of coefficients minus one
+    pub fn degree(&self) -> usize {
+        self.coeffs.len() - 1
+    }
+
+    /// Interval `(a, b)`
+    pub fn interval(&self) -> (f64, f64) {
+        (self.a, self.b)
+    }
+
+    /// De Casteljau's algorithm at `t = (x - a)/(b - a)`,
+    /// returns left and right subdivision coefficients
+    fn de_casteljau(&self, x: f64) -> (Vec<f64>, Vec<f64>) {
+        let n = self.degree();
+        let t = (x - self.a) / (self.b - self.a);
+        let mut beta = self.coeffs.clone();
+        let mut left = Vec::with_capacity(n + 1);
+        let mut right = vec![0.0; n + 1];
+
+        left.push(beta[0]);
+        right[n] = beta[n];
+        for j in 1..=n {
+            for i in 0..=n - j {
+                beta[i] = (1.0 - t) * beta[i] + t * beta[i + 1];
+            }
+            left.push(beta[0]);
+            right[n - j] = beta[n - j];
+        }
+        (left, right)
+    }
+
+    /// Evaluate polynomial at `x` with de Casteljau's algorithm
+    pub fn eval(&self, x: f64) -> f64 {
+        self.de_casteljau(x).0[self.degree()]
+    }
+
+    /// Split at `x` into polynomials on `[a, x]` and `[x, b]`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `x` is not inside `(a, b)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustamath::polynomial::bernstein::Bernstein;
+    /// let p = Bernstein::new(vec![1.0, -1.0, 1.0], 0.0, 1.0);
+    /// let (l, r) = p.subdivide(0.5);
+    /// assert_eq!(l.coeffs(), &[1.0, 0.0, 0.0]);
+    /// assert_eq!(r.coeffs(), &[0.0, 0.0, 1.0]);
+    /// assert_eq!(r.interval(), (0.5, 1.0));
+    /// ```
+    pub fn subdivide(&self, x: f64) -> (Bernstein, Bernstein) {
+        assert!(self.a < x && x < self.b, "subdivision point must be inside the interval");
+        let (left, right) = self.de_casteljau(x);
+        (Bernstein {coeffs: left, a: self.a, b: x}, Bernstein {coeffs: right, a: x, b: self.b})
+    }
+
+    /// Range enclosure `(min, max)` of the polynomial on `[a, b]`,
+    /// smallest and largest coefficient
+    pub fn bounds(&self) -> (f64, f64) {
+        self.coeffs.iter().fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), c| {
+            (lo.min(*c), hi.max(*c))
+        })
+    }
+
+    /// Number of sign changes in coefficients, zeros are skipped
+    ///
+    /// Upper bound of the number of roots in `(a, b)` counted with multiplicity,
+    /// the difference is even. No sign changes means no roots,
+    /// one sign change means exactly one simple root.
+    pub fn sign_changes(&self) -> usize {
+        let mut count = 0;
+        let mut last = 0.0;
+        for c in self.coeffs.iter().filter(|c| **c != 0.0) {
+            if last * c < 0.0 {
+                count += 1;
+            }
+            last = *c;
+        }
+        count
+    }
+
+    /// Isolate real roots in `[a, b]` by subdivision, returns intervals sorted by `x`
+    ///
+    /// Each interval `(lo, hi)` has exactly one simple root if it has
+    /// one sign change, roots found exactly at interval ends are `(x, x)`.
+    /// Intervals narrower than `tolerance` may have a multiple root or a cluster of roots.
+    /// Zero polynomial has no isolated roots.
+    pub fn isolate_roots(&self, tolerance: f64) -> Vec<(f64, f64)> {
+        let mut roots = Vec::new();
+        if self.coeffs.iter().all(|c| *c == 0.0) {
+            return roots;
+        }
+
+        if self.coeffs[0] == 0.0 {
+            roots.push((self.a, self.a));
+        }
+        self.isolate(tolerance, &mut roots);
+        if self.coeffs[self.degree()] == 0.0 {
+            roots.push((self.b, self.b));
+        }
+        roots
+    }
+
+    fn isolate(&self, tolerance: f64, roots: &mut Vec<(f64, f64)>) {
+        match self.sign_changes() {
+            0 => {}
+            1 => roots.push((self.a, self.b)),
+            _ if self.b - self.a <= tolerance => roots.push((self.a, self.b)),
+            _ => {
+                let m = 0.5 * (self.a + self.b);
+                if !(self.a < m && m < self.b) {
+                    // interval can not be split in floating-point
+                    roots.push((self.a, self.b));
+                    return;
+                }
+                let (left, right) = self.subdivide(m);
+                left.isolate(tolerance, roots);
+                if right.coeffs[0] == 0.0 {
+                    roots.push((m, m));
+                }
+                right.isolate(tolerance, roots);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::polynomial::polynomial_n;
+
+    #[test]
+    fn bernstein() {
+        let c = [0.3, -1.2, 0.0, 2.5, 0.75];
+        let p = Bernstein::from_monomial(&c, -1.0, 2.0);
+        assert_eq!(p.degree(), 4);
+        assert_eq!(p.interval(), (-1.0, 2.0));
+        for x in [-1.0, -0.3, 0.0, 1.1, 2.0] {
+            assert_float_absolute_eq!(p.eval(x), polynomial_n(x, &c), 1.0e-13);
+        }
+        for (back, ci) in p.to_monomial().iter().zip(c) {
+            assert_float_absolute_eq!(*back, ci, 1.0e-13);
+        }
+        // end coefficients are end values
+        assert_float_absolute_eq!(p.coeffs()[0], polynomial_n(-1.0, &c), 1.0e-14);
+        assert_float_absolute_eq!(p.coeffs()[4], polynomial_n(2.0, &c), 1.0e-13);
+
+        // subdivision keeps the polynomial
+        let (l, r) = p.subdivide(0.4);
+        assert_eq!((l.interval(), r.interval()), ((-1.0, 0.4), (0.4, 2.0)));
+        for x in [-0.8, 0.1, 0.4] {
+            assert_float_absolute_eq!(l.eval(x), p.eval(x), 1.0e-13);
+        }
+        for x in [0.4, 1.0, 1.9] {
+            assert_float_absolute_eq!(r.eval(x), p.eval(x), 1.0e-13);
+        }
+
+        // range enclosure
+        let (lo, hi) = p.bounds();
+        for i in 0..=100 {
+            let y = p.eval(-1.0 + 3.0 * i as f64 / 100.0);
+            assert!(lo <= y && y <= hi);
+        }
+
+        // root isolation, (x + 0.5)(x - 0.25)(x - 0.3)(x - 1.5)(x^2 + 1)
+        let roots = [-0.5, 0.25, 0.3, 1.5];
+        let mut m = Polynomial::new(vec![1.0, 0.0, 1.0]);
+        for r in roots {
+            m = m * Polynomial::new(vec![-r, 1.0]);
+        }
+        let p = Bernstein::from_monomial(m.coeffs(), -1.0, 2.0);
+        assert!(p.sign_changes() >= 4);
+        let found = p.isolate_roots(1.0e-9);
+        assert_eq!(found.len(), 4);
+        for ((lo, hi), r) in found.iter().zip(roots) {
+            assert!(*lo < r && r < *hi, "{} not in ({}, {})", r, lo, hi);
+            let b = Bernstein::from_monomial(m.coeffs(), *lo, *hi);
+            assert_eq!(b.sign_changes(), 1);
+        }
+
+        // roots at subdivision points and interval ends
+        let p = Bernstein::new(vec![0.0, 1.0, -1.0, 0.0], -1.0, 1.0); // 1.5*(x + 1) x (x - 1)
+        assert_eq!(p.isolate_roots(1.0e-9), vec![(-1.0, -1.0), (-1.0, 1.0), (1.0, 1.0)]);
+        let p = Bernstein::new(vec![1.0, -1.0, 0.0, 1.0, -1.0], -1.0, 1.0); // odd, P(0) = 0
+        assert_eq!(p.isolate_roots(1.0e-9), vec![(-1.0, 0.0), (0.0, 0.0), (0.0, 1.0)]);
+
+        // double root is a narrow interval
+        let found = Bernstein::from_monomial(&[1.0, -2.0, 1.0], 0.0, 3.0).isolate_roots(1.0e-6);
+        assert!(!found.is_empty());
+        for (lo, hi) in found {
+            assert!(lo <= 1.0 && 1.0 <= hi && hi - lo <= 1.0e-6);
+        }
+
+        // no roots and constants
+        assert!(Bernstein::from_monomial(&[1.0, 0.0, 1.0], -5.0, 5.0).isolate_roots(1.0e-9).is_empty());
+        let p = Bernstein::from_monomial(&[2.0], 0.0, 1.0);
+        assert_eq!((p.coeffs(), p.eval(0.5)), (&[2.0][..], 2.0));
+        assert!(Bernstein::new(vec![0.0, 0.0], 0.0, 1.0).isolate_roots(1.0e-9).is_empty());
+    }
+}
@@ -0,0 +1,260 @@
+//! Chebyshev series approximation of functions.
+//!
+//! (c) Igor Lesik 2023
+//! MIT license
+//!
+//! Function on `[a, b]` is approximated by `f(x) = c0/2 + sum(c_k*T_k(y))`,
+//! where `y = (2x - a - b)/(b - a)` maps the interval to `[-1, 1]`.
+//! Coefficients decrease quickly for smooth functions, so the series
+//! can be truncated to a lower order with the error about the first dropped coefficient.
+//! Same conventions as GSL `gsl_cheb_*` functions.
+//!
+//! References:
+//!
+//! - <https://www.gnu.org/software/gsl/doc/html/cheb.html>
+//! - <https://en.wikipedia.org/wiki/Chebyshev_polynomials>
+//! - <https://en.wikipedia.org/wiki/Clenshaw_algorithm>
+//! - William H. Press - Numerical recipes, 5.8 Chebyshev Approximation
+
+use std::f64::consts::PI;
+
+/// Chebyshev series of order `n` on interval `[a, b]`
+///
+/// # Example
+///
+/// ```
+/// # use rustamath::polynomial::chebyshev::ChebSeries;
+/// # use assert_float_eq::*;
+/// let cs = ChebSeries::new(f64::exp, 20, 0.0, 2.0);
+/// assert_float_relative_eq!(cs.eval(1.3), 1.3f64.exp(), 1.0e-15);
+///
+/// // cheap surrogate of lower order with error estimate
+/// let (y, err) = cs.eval_n_err(10, 1.3);
+/// assert!((y - 1.3f64.exp()).abs() <= err);
+/// assert!(err < 1.0e-8);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct ChebSeries {
+    coeffs: Vec<f64>,
+    a: f64,
+    b: f64,
+}
+
+impl ChebSeries {
+    /// Compute Chebyshev series of `order` for function `f` on interval `[a, b]`,
+    /// `f` is evaluated at `order + 1` Chebyshev nodes
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a >= b`.
+    pub fn new<F: Fn(f64) -> f64>(f: F, order: usize, a: f64, b: f64) -> ChebSeries {
+        assert!(a < b, "interval must have a < b");
+
+        let n = order + 1;
+        let bma = 0.5*(b - a);
+        let bpa = 0.5*(b + a);
+        let fac = 2.0/n as f64;
+
+        let fk: Vec<f64> = (0..n)
+            .map(|k| f(bma*(PI*(k as f64 + 0.5)/n as f64).cos() + bpa))
+            .collect();
+
+        let coeffs = (0..n).map(|j| {
+            let sum: f64 = fk.iter().enumerate()
+                .map(|(k, fk)| fk*(PI*j as f64*(k as f64 + 0.5)/n as f64).cos())
+                .sum();
+            fac*sum
+        }).collect();
+
+        ChebSeries {coeffs, a, b}
+    }
+
+    /// Create series from coefficients `c0, c1, ...`, note that `c0` is halved in evaluation
+    pub fn from_coeffs(coeffs: Vec<f64>, a: f64, b: f64) -> ChebSeries {
+        assert!(!coeffs.is_empty(), "series must have at least one coefficient");
+        assert!(a < b, "interval must have a < b");
+        ChebSeries {coeffs, a, b}
+    }
+
+    /// Coefficients `c0, c1, ...`
+    pub fn coeffs(&self) -> &[f64] {
+        &self.coeffs
+    }
+
+    /// Order of the series, number of coefficients minus one
+    pub fn order(&self) -> usize {
+        self.coeffs.len() - 1
+    }
+
+    /// Interval `(a, b)`
+    pub fn interval(&self) -> (f64, f64) {
+        (self.a, self.b)
+    }
+
+    /// Evaluate series at `x`
+    pub fn eval(&self, x: f64) -> f64 {
+        self.clenshaw(self.order(), x)
+    }
+
+    /// Evaluate series at `x`, returns `(value, error estimate)`
+    pub fn eval_err(&self, x: f64) -> (f64, f64) {
+        self.eval_n_err(self.order(), x)
+    }
+
+    /// Evaluate series truncated to `order` at `x`
+    pub fn eval_n(&self, order: usize, x: f64) -> f64 {
+        self.clenshaw(order.min(self.order()), x)
+    }
+
+    /// Evaluate series truncated to `order` at `x`, returns `(value, error estimate)`
+    ///
+    /// Error is the last used coefficient, which bounds the truncation error for
+    /// quickly converging series, plus the rounding error.
+    pub fn eval_n_err(&self, order: usize, x: f64) -> (f64, f64) {
+        let order = order.min(self.order());
+        let y = self.clenshaw(order, x);
+        let absc: f64 = self.coeffs[..=order].iter().map(|c| c.abs()).sum();
+        (y, self.coeffs[order].abs() + absc*f64::EPSILON)
+    }
+
+    /// Clenshaw's recurrence `b_k = 2y*b(k+1) - b(k+2) + c_k`
+    fn clenshaw(&self, order: usize, x: f64) -> f64 {
+        let c = &self.coeffs;
+        let y = (2.0*x - self.a - self.b)/(self.b - self.a);
+        let y2 = 2.0*y;
+
+        let (mut d1, mut d2) = (0.0, 0.0);
+        for ci in c[1..=order].iter().rev() {
+            (d1, d2) = (y2*d1 - d2 + ci, d1);
+        }
+        y*d1 - d2 + 0.5*c[0]
+    }
+
+    /// Series of the derivative `f'(x)` on the same interval
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustamath::polynomial::chebyshev::ChebSeries;
+    /// # use assert_float_eq::*;
+    /// let cs = ChebSeries::new(f64::sin, 25, -1.0, 2.0);
+    /// assert_float_absolute_eq!(cs.derivative().eval(0.5), 0.5f64.cos(), 1.0e-13);
+    /// ```
+    pub fn derivative(&self) -> ChebSeries {
+        let c = &self.coeffs;
+        let n = c.len();
+        let con = 2.0/(self.b - self.a);
+        let mut d = vec![0.0; n];
+
+        if n > 1 {
+            d[n - 2] = 2.0*(n - 1) as f64*c[n - 1];
+            for i in (3..=n).rev() {
+                d[i - 3] = d[i - 1] + 2.0*(i - 2) as f64*c[i - 2];
+            }
+            for di in d.iter_mut() {
+                *di *= con;
+            }
+        }
+
+        ChebSeries {coeffs: d, a: self.a, b: self.b}
+    }
+
+    /// Series of the integral of `f` from `a` to `x` on the same interval
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustamath::polynomial::chebyshev::ChebSeries;
+    /// # use assert_float_eq::*;
+    /// let cs = ChebSeries::new(f64::cos, 25, 0.0, 3.0);
+    /// let int = cs.integral();
+    /// assert_float_absolute_eq!(int.eval(0.0), 0.0, 1.0e-15);
+    /// assert_float_absolute_eq!(int.eval(2.5), 2.5f64.sin(), 1.0e-14);
+    /// ```
+    pub fn integral(&self) -> ChebSeries {
+        let c = &self.coeffs;
+        let n = c.len();
+        let con = 0.25*(self.b - self.a);
+        let mut int = vec![0.0; n];
+
+        if n == 2 {
+            int[1] = con*c[0];
+            int[0] = 2.0*int[1];
+        } else if n > 2 {
+            // constant term makes the integral zero at a, where T_k(-1) = (-1)^k
+            let mut sum = 0.0;
+            let mut fac = 1.0;
+            for i in 1..n - 1 {
+                int[i] = con*(c[i - 1] - c[i + 1])/i as f64;
+                sum += fac*int[i];
+                fac = -fac;
+            }
+            int[n - 1] = con*c[n - 2]/(n - 1) as f64;
+            sum += fac*int[n - 1];
+            int[0] = 2.0*sum;
+        }
+
+        ChebSeries {coeffs: int, a: self.a, b: self.b}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::polynomial::orthogonal::OrthogonalPolynomial;
+
+    #[test]
+    fn chebyshev_series() {
+        // polynomial is represented exactly
+        let f = |x: f64| 1.0 - 2.0*x + 0.5*x*x*x;
+        let cs = ChebSeries::new(f, 5, -3.0, 1.0);
+        for x in [-3.0, -1.7, 0.0, 0.4, 1.0] {
+            let (y, err) = cs.eval_err(x);
+            assert_float_absolute_eq!(y, f(x), 1.0e-13);
+            assert!(err < 1.0e-13);
+        }
+        assert!(cs.coeffs()[4].abs() < 1.0e-14);
+
+        // same as sum of Chebyshev polynomials on [-1, 1]
+        let cs = ChebSeries::from_coeffs(vec![0.6, -0.3, 0.2, 0.1], -1.0, 1.0);
+        let t = OrthogonalPolynomial::ChebyshevT;
+        for x in [-0.8, 0.1, 0.9] {
+            let expected = 0.3 - 0.3*t.eval(1, x) + 0.2*t.eval(2, x) + 0.1*t.eval(3, x);
+            assert_float_absolute_eq!(cs.eval(x), expected, 1.0e-15);
+            assert_float_absolute_eq!(cs.eval_n(1, x), 0.3 - 0.3*x, 1.0e-15);
+        }
+        assert_eq!(cs.eval_n(10, 0.3), cs.eval(0.3));
+
+        // truncation error estimate covers the real error
+        let g = |x: f64| (x*x).sin() + 1.0/(2.0 + x);
+        let cs = ChebSeries::new(g, 40, -1.0, 1.5);
+        assert_eq!(cs.order(), 40);
+        assert_eq!(cs.interval(), (-1.0, 1.5));
+        for order in [8, 12, 16, 20] {
+            for x in [-0.9, -0.2, 0.6, 1.4] {
+                let (y, err) = cs.eval_n_err(order, x);
+                assert!((y - g(x)).abs() <= 10.0*err, "order {} x {}", order, x);
+            }
+        }
+
+        // derivative and integral series
+        let cs = ChebSeries::new(f64::exp, 25, -0.5, 1.5);
+        let d = cs.derivative();
+        let d2 = d.derivative();
+        let int = cs.integral();
+        for x in [-0.5, 0.1, 0.7, 1.5] {
+            assert_float_relative_eq!(d.eval(x), x.exp(), 1.0e-12);
+            assert_float_relative_eq!(d2.eval(x), x.exp(), 1.0e-8);
+            assert_float_absolute_eq!(int.eval(x), x.exp() - (-0.5f64).exp(), 1.0e-14);
+        }
+        assert_float_absolute_eq!(int.derivative().eval(0.3), 0.3f64.exp(), 1.0e-12);
+
+        // low orders
+        let cs = ChebSeries::from_coeffs(vec![4.0], 0.0, 1.0);
+        assert_eq!(cs.eval(0.3), 2.0);
+        assert_eq!(cs.derivative().coeffs(), &[0.0]);
+        assert_eq!(cs.integral().coeffs(), &[0.0]);
+        let cs = ChebSeries::from_coeffs(vec![4.0, 1.0], 0.0, 1.0);
+        assert_float_absolute_eq!(cs.integral().eval(1.0), cs.eval(0.5), 1.0e-15);
+    }
+}
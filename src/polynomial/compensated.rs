@@ -0,0 +1,140 @@
+//! Compensated Horner's method.
+//!
+//! (c) Igor Lesik 2023
+//! MIT license
+//!
+//! Rounding errors of each Horner step are captured exactly with
+//! [`two_product`] and [`two_sum`] and accumulated in a second Horner evaluation.
+//! The result is as accurate as Horner's method in doubled working precision
+//! followed by rounding, relative error is about `u + cond(P, x)*u^2`.
+//!
+//! References:
+//!
+//! - S. Graillat, N. Louvet, Ph. Langlois, Compensated Horner Scheme, 2005
+//! - Ph. Langlois, N. Louvet, How to Ensure a Faithful Polynomial Evaluation
+//!   with the Compensated Horner Algorithm, ARITH-18, 2007
+//! - <https://en.wikipedia.org/wiki/Horner%27s_method>
+
+use crate::function::{two_sum, two_product};
+
+/// Unit roundoff
+const U: f64 = f64::EPSILON / 2.0;
+
+/// `n*u/(1 - n*u)`
+fn gamma(n: usize) -> f64 {
+    let nu = n as f64 * U;
+    nu / (1.0 - nu)
+}
+
+/// Horner's evaluation with error-free transformations, returns `(value, |errors| at |x|)`
+fn comp_horner(x: f64, cs: &[f64]) -> (f64, f64) {
+    let n = cs.len() - 1;
+    let mut s = cs[n];
+    let mut c = 0.0;
+    let mut e = 0.0;
+    let ax = x.abs();
+
+    for ci in cs.iter().take(n).rev() {
+        let (p, pi) = two_product(s, x);
+        let (sum, sigma) = two_sum(p, *ci);
+        s = sum;
+        c = c*x + (pi + sigma);
+        e = e*ax + (pi.abs() + sigma.abs());
+    }
+
+    (s + c, e)
+}
+
+/// Polynomial `c0 + c1*x + c2*x^2 + ...` with compensated Horner's method
+///
+/// Same as [`polynomial_n`](super::polynomial_n), but accurate even for ill-conditioned
+/// evaluation, like expanded `(x - 1)^7` near `x = 1`.
+///
+/// # Example
+///
+/// ```
+/// # use rustamath::polynomial::*;
+/// // (x - 1)^3 = x^3 - 3x^2 + 3x - 1
+/// let c = [-1.0, 3.0, -3.0, 1.0];
+/// let x = 1.0 + 1.0e-6;
+/// let exact = (x - 1.0f64).powi(3);
+/// assert!((compensated_polynomial_n(x, &c) - exact).abs() < 1.0e-10 * exact);
+/// assert!((polynomial_n(x, &c) - exact).abs() > 1.0e-3 * exact);
+/// ```
+pub fn compensated_polynomial_n(x: f64, cs: &[f64]) -> f64 {
+    comp_horner(x, cs).0
+}
+
+/// Compensated Horner's method with a posteriori error bound, returns `(P(x), bound)`
+///
+/// `|result - P(x)| <= bound`, computed in floating-point and valid if no underflow occurs.
+/// The sign of the result is correct if `|result| > bound`,
+/// bracketing root finders need the sign to be trustworthy.
+///
+/// # Example
+///
+/// ```
+/// # use rustamath::polynomial::*;
+/// // (x - 1)^7 expanded
+/// let c = [-1.0, 7.0, -21.0, 35.0, -35.0, 21.0, -7.0, 1.0];
+///
+/// let (y, bound) = compensated_polynomial_n_bound(1.001, &c);
+/// assert!(y > bound); // sign is certain
+///
+/// let (y, bound) = compensated_polynomial_n_bound(1.0 + 1.0e-6, &c);
+/// assert!(y.abs() <= bound); // too close to the root, sign is unknown
+/// ```
+pub fn compensated_polynomial_n_bound(x: f64, cs: &[f64]) -> (f64, f64) {
+    let n = cs.len() - 1;
+    let (r, e) = comp_horner(x, cs);
+
+    let bound = (gamma(4*n + 2) * e + 2.0 * U * U * r.abs()) / (1.0 - 2.0 * (n + 1) as f64 * U);
+
+    (r, U * r.abs() + bound)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::polynomial::polynomial_n;
+
+    #[test]
+    fn compensated_horner() {
+        // (x - 1)^7 expanded, x - 1 is exact near 1
+        let c = [-1.0, 7.0, -21.0, 35.0, -35.0, 21.0, -7.0, 1.0];
+
+        for k in -50i32..=50 {
+            if k == 0 {
+                continue;
+            }
+            let x = 1.0 + k.signum() as f64 * 1.0e-3 + k as f64 * 2.0e-4;
+            let exact = (x - 1.0f64).powi(7);
+
+            let y = compensated_polynomial_n(x, &c);
+            assert!((y - exact).abs() <= 1.0e-6 * exact.abs(), "x={} y={} exact={}", x, y, exact);
+
+            let (yb, bound) = compensated_polynomial_n_bound(x, &c);
+            assert_eq!(y, yb);
+            assert!((y - exact).abs() <= bound);
+            assert!(bound < exact.abs());
+            assert_eq!(y.signum(), exact.signum());
+        }
+
+        // plain Horner is wrong even in sign
+        let x = 1.0 + 1.0e-3;
+        let exact = (x - 1.0f64).powi(7);
+        assert!((polynomial_n(x, &c) - exact).abs() > exact.abs());
+        assert!((compensated_polynomial_n(x, &c) - exact).abs() < 1.0e-6 * exact.abs());
+
+        // well conditioned evaluation, bound is about u*|P(x)|
+        let c = [1.1, 2.2, 3.3, 4.4, 5.5, 6.6];
+        let x = 0.12345678;
+        let (y, bound) = compensated_polynomial_n_bound(x, &c);
+        assert_f64_near!(y, polynomial_n(x, &c), 8);
+        assert!(bound < 4.0 * f64::EPSILON * y.abs());
+
+        let (y, bound) = compensated_polynomial_n_bound(3.0, &[2.5]);
+        assert_eq!(y, 2.5);
+        assert!(bound < 2.5 * f64::EPSILON);
+    }
+}
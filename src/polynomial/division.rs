@@ -0,0 +1,315 @@
+//! Polynomial division, GCD and square-free factorization.
+//!
+//! (c) Igor Lesik 2023
+//! MIT license
+//!
+//! References:
+//!
+//! - <https://en.wikipedia.org/wiki/Polynomial_long_division>
+//! - <https://en.wikipedia.org/wiki/Synthetic_division>
+//! - <https://en.wikipedia.org/wiki/Polynomial_greatest_common_divisor>
+//! - <https://en.wikipedia.org/wiki/Square-free_polynomial#Yun's_algorithm>
+//! - William H. Press - Numerical recipes, 5.1.4 Polynomial Division
+
+use std::ops::{Div, Rem};
+use num_traits::{Num, Float};
+use super::Polynomial;
+
+impl<T: Num + Clone> Polynomial<T> {
+    /// Euclidean division `self = quotient * divisor + remainder`,
+    /// degree of the remainder is less than degree of the divisor
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is zero polynomial.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustamath::polynomial::Polynomial;
+    /// // x^3 - 2x^2 - 4 = (x - 3)(x^2 + x + 3) + 5
+    /// let p = Polynomial::new(vec![-4.0, 0.0, -2.0, 1.0]);
+    /// let (q, r) = p.div_rem(&Polynomial::new(vec![-3.0, 1.0]));
+    /// assert_eq!(q.coeffs(), &[3.0, 1.0, 1.0]);
+    /// assert_eq!(r.coeffs(), &[5.0]);
+    /// ```
+    pub fn div_rem(&self, divisor: &Polynomial<T>) -> (Polynomial<T>, Polynomial<T>) {
+        assert!(!divisor.is_zero(), "division by zero polynomial");
+
+        let n = self.degree();
+        let m = divisor.degree();
+
+        if n < m {
+            return (Polynomial::zero(), self.clone());
+        }
+
+        let d = divisor.coeffs();
+        let lead = divisor.leading_coeff();
+        let mut rem = self.coeffs().to_vec();
+        let mut quot = vec![T::zero(); n - m + 1];
+
+        for k in (0..=n - m).rev() {
+            let q = rem[k + m].clone() / lead.clone();
+            for (j, dj) in d.iter().take(m).enumerate() {
+                rem[k + j] = rem[k + j].clone() - q.clone() * dj.clone();
+            }
+            // eliminated exactly, do not keep rounding error
+            rem[k + m] = T::zero();
+            quot[k] = q;
+        }
+
+        rem.truncate(m.max(1));
+
+        (Polynomial::new(quot), Polynomial::new(rem))
+    }
+
+    /// Synthetic division by `(x - r)`, returns quotient and remainder `P(r)`
+    ///
+    /// When `r` is a root, the quotient is the deflated polynomial
+    /// which has all other roots.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustamath::polynomial::Polynomial;
+    /// // x^2 - 3x + 2 = (x - 1)(x - 2)
+    /// let p = Polynomial::new(vec![2.0, -3.0, 1.0]);
+    /// let (q, r) = p.deflate(1.0);
+    /// assert_eq!(q.coeffs(), &[-2.0, 1.0]);
+    /// assert_eq!(r, 0.0);
+    /// ```
+    pub fn deflate(&self, r: T) -> (Polynomial<T>, T) {
+        let c = self.coeffs();
+        let n = c.len();
+
+        if n == 1 {
+            return (Polynomial::zero(), c[0].clone());
+        }
+
+        let mut quot = vec![T::zero(); n - 1];
+        let mut acc = c[n - 1].clone();
+
+        for i in (0..n - 1).rev() {
+            quot[i] = acc.clone();
+            acc = c[i].clone() + acc * r.clone();
+        }
+
+        (Polynomial::new(quot), acc)
+    }
+}
+
+/// Largest absolute value of coefficients
+fn max_abs<T: Float>(p: &Polynomial<T>) -> T {
+    p.coeffs().iter().fold(T::zero(), |m, c| m.max(c.abs()))
+}
+
+/// Replace coefficients not larger than `threshold` with zeros
+fn chop<T: Float>(p: &Polynomial<T>, threshold: T) -> Polynomial<T> {
+    Polynomial::new(p.coeffs().iter()
+        .map(|c| if c.abs() <= threshold { T::zero() } else { *c })
+        .collect())
+}
+
+/// Make leading coefficient 1
+fn monic<T: Float>(p: &Polynomial<T>) -> Polynomial<T> {
+    if p.is_zero() {
+        return p.clone();
+    }
+    p * (T::one() / p.leading_coeff())
+}
+
+impl<T: Float> Polynomial<T> {
+    /// Greatest common divisor, monic
+    ///
+    /// Euclid's algorithm where remainder coefficients smaller than
+    /// `tolerance * max|dividend coefficient|` are treated as zeros,
+    /// this keeps rounding errors from turning common roots into a constant GCD.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustamath::polynomial::Polynomial;
+    /// # use assert_float_eq::*;
+    /// let p = Polynomial::new(vec![-1.0, 0.0, 1.0]);        // (x - 1)(x + 1)
+    /// let q = Polynomial::new(vec![-2.0, 1.0, 1.0]);        // (x - 1)(x + 2)
+    /// let g = p.gcd(&q, 1.0e-12);
+    /// assert_eq!(g.degree(), 1);
+    /// assert_float_absolute_eq!(g.coeffs()[0], -1.0, 1.0e-15);
+    /// ```
+    pub fn gcd(&self, other: &Polynomial<T>, tolerance: T) -> Polynomial<T> {
+        let (mut a, mut b) = if self.degree() >= other.degree() {
+            (monic(self), monic(other))
+        } else {
+            (monic(other), monic(self))
+        };
+
+        while !b.is_zero() {
+            let (_, r) = a.div_rem(&b);
+            let r = chop(&r, tolerance * max_abs(&a).max(max_abs(&b)));
+            a = b;
+            b = monic(&r);
+        }
+
+        a
+    }
+
+    /// Square-free factorization `P = lc * f1 * f2^2 * f3^3 * ...`
+    ///
+    /// Returns monic square-free factors `fi` with multiplicities `i`,
+    /// constant factors are skipped, roots of each `fi` are simple roots of `P`
+    /// with multiplicity `i`. Yun's algorithm, `tolerance` is passed to [`Polynomial::gcd`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustamath::polynomial::Polynomial;
+    /// // (x - 1)^2 * (x + 1) = x^3 - x^2 - x + 1
+    /// let p = Polynomial::new(vec![1.0, -1.0, -1.0, 1.0]);
+    /// let factors = p.square_free(1.0e-12);
+    /// assert_eq!(factors.len(), 2);
+    /// assert_eq!((factors[0].0.coeffs(), factors[0].1), (&[1.0, 1.0][..], 1));
+    /// assert_eq!((factors[1].0.coeffs(), factors[1].1), (&[-1.0, 1.0][..], 2));
+    /// ```
+    pub fn square_free(&self, tolerance: T) -> Vec<(Polynomial<T>, usize)> {
+        let mut factors = Vec::new();
+
+        if self.degree() == 0 {
+            return factors;
+        }
+
+        let f = monic(self);
+        let df = f.derivative();
+        let a = f.gcd(&df, tolerance);
+
+        // `d = c - b'` is zero when all roots are found,
+        // rounding errors must not make it a non-zero polynomial
+        let next_d = |c: &Polynomial<T>, b: &Polynomial<T>| {
+            let db = b.derivative();
+            chop(&(c - &db), tolerance * max_abs(c).max(max_abs(&db)))
+        };
+
+        let mut b = monic(&(&f / &a));
+        let mut d = next_d(&(&df / &a), &b);
+        let mut i = 1;
+
+        // multiplicity can not exceed the degree
+        while b.degree() > 0 && i <= f.degree() {
+            let a = b.gcd(&d, tolerance);
+            if a.degree() > 0 {
+                factors.push((a.clone(), i));
+            }
+            b = monic(&(&b / &a));
+            d = next_d(&(&d / &a), &b);
+            i += 1;
+        }
+
+        factors
+    }
+}
+
+impl<T: Num + Clone> Div for &Polynomial<T> {
+    type Output = Polynomial<T>;
+
+    fn div(self, divisor: &Polynomial<T>) -> Polynomial<T> {
+        self.div_rem(divisor).0
+    }
+}
+
+impl<T: Num + Clone> Rem for &Polynomial<T> {
+    type Output = Polynomial<T>;
+
+    fn rem(self, divisor: &Polynomial<T>) -> Polynomial<T> {
+        self.div_rem(divisor).1
+    }
+}
+
+impl<T: Num + Clone> Div for Polynomial<T> {
+    type Output = Polynomial<T>;
+
+    fn div(self, divisor: Polynomial<T>) -> Polynomial<T> {
+        self.div_rem(&divisor).0
+    }
+}
+
+impl<T: Num + Clone> Rem for Polynomial<T> {
+    type Output = Polynomial<T>;
+
+    fn rem(self, divisor: Polynomial<T>) -> Polynomial<T> {
+        self.div_rem(&divisor).1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::polynomial::test_helpers::from_roots;
+
+    #[test]
+    fn division() {
+        let p = Polynomial::new(vec![1.0, 2.0, 3.0, 4.0, 5.0]);
+        let d = Polynomial::new(vec![-1.0, 0.5, 2.0]);
+        let (q, r) = p.div_rem(&d);
+        assert!(r.degree() < d.degree());
+        let back = &(&q * &d) + &r;
+        for (a, b) in back.coeffs().iter().zip(p.coeffs()) {
+            assert_float_absolute_eq!(a, b, 1.0e-14);
+        }
+        assert_eq!(&p / &d, q);
+        assert_eq!(&p % &d, r);
+
+        // divisor of higher degree
+        let (q, r) = d.div_rem(&p);
+        assert!(q.is_zero());
+        assert_eq!(r, d);
+
+        // deflate all roots in turn
+        let mut p = from_roots(&[1.0, -2.0, 3.0, 0.5]);
+        for root in [3.0, 0.5, -2.0] {
+            let (q, r) = p.deflate(root);
+            assert_float_absolute_eq!(r, 0.0, 1.0e-13);
+            assert_float_absolute_eq!(r, p.eval(root), 1.0e-13);
+            p = q;
+        }
+        assert_eq!(p.degree(), 1);
+        assert_float_absolute_eq!(p.coeffs()[0], -1.0, 1.0e-13);
+    }
+
+    #[test]
+    fn gcd() {
+        let p = from_roots(&[1.0, 2.0, 3.0, -0.25]);
+        let q = from_roots(&[2.0, -0.25, 5.0]);
+        let g = p.gcd(&q, 1.0e-10);
+        let expected = from_roots(&[2.0, -0.25]);
+        assert_eq!(g.degree(), 2);
+        for (a, b) in g.coeffs().iter().zip(expected.coeffs()) {
+            assert_float_absolute_eq!(a, b, 1.0e-12);
+        }
+
+        // coprime
+        assert_eq!(p.gcd(&from_roots(&[4.0, 7.0]), 1.0e-10).degree(), 0);
+        // with zero
+        assert_eq!(p.gcd(&Polynomial::zero(), 1.0e-10).degree(), 4);
+    }
+
+    #[test]
+    fn square_free() {
+        // (x - 1)^3 * (x + 2)^2 * (x - 3)
+        let p = from_roots(&[1.0, 1.0, 1.0, -2.0, -2.0, 3.0]) * 2.0;
+        let factors = p.square_free(1.0e-10);
+        assert_eq!(factors.len(), 3);
+        for ((f, i), (root, m)) in factors.iter().zip([(3.0, 1), (-2.0, 2), (1.0, 3)]) {
+            assert_eq!(*i, m);
+            assert_eq!(f.degree(), 1);
+            assert_float_absolute_eq!(-f.coeffs()[0], root, 1.0e-8);
+        }
+
+        // already square-free
+        let p = from_roots(&[1.0, 2.0, 3.0]);
+        let factors = p.square_free(1.0e-10);
+        assert_eq!(factors.len(), 1);
+        assert_eq!(factors[0].0.degree(), 3);
+        assert_eq!(factors[0].1, 1);
+
+        assert!(Polynomial::constant(3.0).square_free(1.0e-10).is_empty());
+    }
+}
@@ -0,0 +1,301 @@
+//! Weighted least-squares polynomial fit.
+//!
+//! (c) Igor Lesik 2023
+//! MIT license
+//!
+//! Minimizes `chi^2 = sum(w_i * (y_i - P(x_i))^2)` with Householder QR
+//! of the weighted Vandermonde matrix, normal equations are never formed.
+//! To keep the Vandermonde matrix well conditioned `x` is mapped to `[-1, 1]`,
+//! coefficients and covariance are converted back to powers of `x` at the end.
+//!
+//! References:
+//!
+//! - <https://www.gnu.org/software/gsl/doc/html/lls.html#multi-parameter-regression>
+//! - <https://en.wikipedia.org/wiki/Polynomial_regression>
+//! - <https://en.wikipedia.org/wiki/QR_decomposition#Using_Householder_reflections>
+//! - William H. Press - Numerical recipes, 15.4 General Linear Least Squares
+
+/// Polynomial fit errors
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FitErr {
+    /// Different number of x, y or weight values
+    LengthMismatch,
+    /// Fewer points with positive weight than coefficients
+    NotEnoughPoints,
+    /// Weight is negative or not finite
+    BadWeight,
+    /// Columns of Vandermonde matrix are linearly dependent, x values are not distinct enough
+    RankDeficient,
+}
+
+/// Result of [`polynomial_fit`]
+#[derive(Debug, Clone)]
+pub struct PolynomialFit {
+    /// Coefficients `c0, c1, c2...` for [`polynomial_n`](super::polynomial_n)
+    pub coeffs: Vec<f64>,
+    /// Covariance matrix of the coefficients, row-major `(degree+1) x (degree+1)`
+    pub covariance: Vec<f64>,
+    /// Weighted sum of squared residuals
+    pub chi_square: f64,
+    /// Coefficient of determination `1 - chi^2/TSS`
+    pub r_squared: f64,
+    /// Degrees of freedom, number of points with positive weight minus number of coefficients
+    pub dof: usize,
+}
+
+impl PolynomialFit {
+    /// Standard errors of the coefficients, square roots of covariance diagonal
+    pub fn std_errors(&self) -> Vec<f64> {
+        let m = self.coeffs.len();
+        (0..m).map(|k| self.covariance[k*m + k].sqrt()).collect()
+    }
+}
+
+/// Fit polynomial of `degree` to points `(x_i, y_i)` with optional weights `w_i = 1/sigma_i^2`
+///
+/// Same convention as GSL `gsl_multifit_wlinear` and `gsl_multifit_linear`:
+/// with weights covariance is `(A^T W A)^-1`, without weights it is scaled
+/// by `chi^2/dof` estimated from the residuals.
+/// Points with zero weight are ignored.
+///
+/// # Example
+///
+/// ```
+/// # use rustamath::polynomial::fit::*;
+/// # use assert_float_eq::*;
+/// let xs = [0.0, 1.0, 2.0, 3.0, 4.0];
+/// let ys = [1.1, 2.9, 5.2, 6.8, 9.1];  // about 1 + 2x
+/// let fit = polynomial_fit(&xs, &ys, None, 1).unwrap();
+/// assert_float_absolute_eq!(fit.coeffs[0], 1.04, 1.0e-12);
+/// assert_float_absolute_eq!(fit.coeffs[1], 1.99, 1.0e-12);
+/// assert!(fit.r_squared > 0.99);
+/// assert_eq!(fit.dof, 3);
+/// ```
+pub fn polynomial_fit(
+    xs: &[f64],
+    ys: &[f64],
+    ws: Option<&[f64]>,
+    degree: usize,
+) -> Result<PolynomialFit, FitErr> {
+    let n = xs.len();
+    let m = degree + 1;
+
+    if ys.len() != n || ws.is_some_and(|ws| ws.len() != n) {
+        return Err(FitErr::LengthMismatch);
+    }
+
+    let w: Vec<f64> = match ws {
+        Some(ws) => ws.to_vec(),
+        None => vec![1.0; n],
+    };
+    if w.iter().any(|w| !w.is_finite() || *w < 0.0) {
+        return Err(FitErr::BadWeight);
+    }
+
+    let nr_points = w.iter().filter(|w| **w > 0.0).count();
+    if nr_points < m {
+        return Err(FitErr::NotEnoughPoints);
+    }
+
+    // t = alpha*x + beta maps [min x, max x] to [-1, 1]
+    let x_min = xs.iter().fold(f64::INFINITY, |a, x| a.min(*x));
+    let x_max = xs.iter().fold(f64::NEG_INFINITY, |a, x| a.max(*x));
+    let (alpha, beta) = if x_max > x_min {
+        let alpha = 2.0 / (x_max - x_min);
+        (alpha, -1.0 - alpha * x_min)
+    } else {
+        (1.0, 0.0)
+    };
+
+    // weighted Vandermonde matrix and right hand side
+    let mut a = vec![0.0; n * m];
+    let mut b = vec![0.0; n];
+    for i in 0..n {
+        let sw = w[i].sqrt();
+        let t = alpha * xs[i] + beta;
+        let mut tk = sw;
+        for k in 0..m {
+            a[i*m + k] = tk;
+            tk *= t;
+        }
+        b[i] = sw * ys[i];
+    }
+
+    householder_qr(&mut a, &mut b, n, m);
+
+    // R is upper triangular in the first m rows
+    let r_max = (0..m).fold(0.0f64, |r, k| r.max(a[k*m + k].abs()));
+    if (0..m).any(|k| a[k*m + k].abs() <= n as f64 * f64::EPSILON * r_max) {
+        return Err(FitErr::RankDeficient);
+    }
+
+    // back substitution R*c = Q^T*b
+    let mut c = vec![0.0; m];
+    for k in (0..m).rev() {
+        let s = (k + 1..m).fold(b[k], |s, j| s - a[k*m + j] * c[j]);
+        c[k] = s / a[k*m + k];
+    }
+
+    // covariance (R^T R)^-1 = R^-1 R^-T
+    let mut r_inv = vec![0.0; m * m];
+    for col in 0..m {
+        for k in (0..=col).rev() {
+            let e = if k == col { 1.0 } else { 0.0 };
+            let s = (k + 1..=col).fold(e, |s, j| s - a[k*m + j] * r_inv[j*m + col]);
+            r_inv[k*m + col] = s / a[k*m + k];
+        }
+    }
+    let mut cov = vec![0.0; m * m];
+    for i in 0..m {
+        for j in 0..m {
+            cov[i*m + j] = (i.max(j)..m).map(|k| r_inv[i*m + k] * r_inv[j*m + k]).sum();
+        }
+    }
+
+    // convert from powers of t to powers of x, coeffs_x = T * coeffs_t
+    let tm = scaling_matrix(alpha, beta, m);
+    let coeffs: Vec<f64> = (0..m).map(|i| (0..m).map(|k| tm[i*m + k] * c[k]).sum()).collect();
+    let mut tc = vec![0.0; m * m];
+    for i in 0..m {
+        for j in 0..m {
+            tc[i*m + j] = (0..m).map(|k| tm[i*m + k] * cov[k*m + j]).sum();
+        }
+    }
+    let mut covariance = vec![0.0; m * m];
+    for i in 0..m {
+        for j in 0..m {
+            covariance[i*m + j] = (0..m).map(|k| tc[i*m + k] * tm[j*m + k]).sum();
+        }
+    }
+
+    // goodness of fit
+    let chi_square: f64 = (0..n)
+        .map(|i| w[i] * (ys[i] - super::polynomial_n(xs[i], &coeffs)).powi(2))
+        .sum();
+    let w_sum: f64 = w.iter().sum();
+    let y_mean = (0..n).map(|i| w[i] * ys[i]).sum::<f64>() / w_sum;
+    let tss: f64 = (0..n).map(|i| w[i] * (ys[i] - y_mean).powi(2)).sum();
+    let r_squared = if tss > 0.0 { 1.0 - chi_square / tss } else { 1.0 };
+
+    let dof = nr_points - m;
+    if ws.is_none() && dof > 0 {
+        let sigma2 = chi_square / dof as f64;
+        for v in covariance.iter_mut() {
+            *v *= sigma2;
+        }
+    }
+
+    Ok(PolynomialFit {coeffs, covariance, chi_square, r_squared, dof})
+}
+
+/// Householder QR of row-major `n x m` matrix `a` in place, `b` is replaced by `Q^T b`
+fn householder_qr(a: &mut [f64], b: &mut [f64], n: usize, m: usize) {
+    let mut v = vec![0.0; n];
+
+    for k in 0..m {
+        let norm = (k..n).map(|i| a[i*m + k] * a[i*m + k]).sum::<f64>().sqrt();
+        if norm == 0.0 {
+            continue;
+        }
+        let alpha = if a[k*m + k] > 0.0 { -norm } else { norm };
+
+        for i in k..n {
+            v[i] = a[i*m + k];
+        }
+        v[k] -= alpha;
+        let v_norm2: f64 = (k..n).map(|i| v[i] * v[i]).sum();
+        if v_norm2 == 0.0 {
+            continue;
+        }
+
+        // apply I - 2*v*v^T/(v^T*v) to the remaining columns and to b
+        for j in k..m {
+            let s: f64 = (k..n).map(|i| v[i] * a[i*m + j]).sum();
+            let f = 2.0 * s / v_norm2;
+            for i in k..n {
+                a[i*m + j] -= f * v[i];
+            }
+        }
+        let s: f64 = (k..n).map(|i| v[i] * b[i]).sum();
+        let f = 2.0 * s / v_norm2;
+        for i in k..n {
+            b[i] -= f * v[i];
+        }
+    }
+}
+
+/// Row-major `m x m` matrix `T` with `sum(c_k * (alpha*x + beta)^k) = sum((T*c)_i * x^i)`
+fn scaling_matrix(alpha: f64, beta: f64, m: usize) -> Vec<f64> {
+    let mut tm = vec![0.0; m * m];
+    // column k holds coefficients of (alpha*x + beta)^k
+    let mut p = vec![0.0; m];
+    p[0] = 1.0;
+    for k in 0..m {
+        for i in 0..m {
+            tm[i*m + k] = p[i];
+        }
+        for i in (0..m).rev() {
+            p[i] = beta * p[i] + if i > 0 { alpha * p[i - 1] } else { 0.0 };
+        }
+    }
+    tm
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::polynomial::polynomial_n;
+
+    #[test]
+    fn least_squares_fit() {
+        // exact cubic, far from the origin
+        let f = |x: f64| 3.0 - 0.5 * x + 0.02 * x * x - 1.0e-4 * x * x * x;
+        let xs: Vec<f64> = (0..40).map(|i| 100.0 + i as f64 * 2.5).collect();
+        let ys: Vec<f64> = xs.iter().map(|x| f(*x)).collect();
+        let fit = polynomial_fit(&xs, &ys, None, 3).expect("fit failed");
+        for x in [100.0, 131.0, 197.5] {
+            assert_float_relative_eq!(polynomial_n(x, &fit.coeffs), f(x), 1.0e-9);
+        }
+        assert_float_absolute_eq!(fit.r_squared, 1.0, 1.0e-12);
+        assert_eq!(fit.dof, 36);
+
+        // straight line with known sigma, compare with closed form solution
+        let xs = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+        let ys = [2.1, 3.9, 6.2, 7.8, 10.1, 12.2];
+        let sigma = [0.1, 0.2, 0.1, 0.3, 0.2, 0.1];
+        let ws: Vec<f64> = sigma.iter().map(|s| 1.0 / (s * s)).collect();
+        let fit = polynomial_fit(&xs, &ys, Some(&ws), 1).expect("fit failed");
+
+        let s: f64 = ws.iter().sum();
+        let sx: f64 = ws.iter().zip(xs).map(|(w, x)| w * x).sum();
+        let sy: f64 = ws.iter().zip(ys).map(|(w, y)| w * y).sum();
+        let sxx: f64 = ws.iter().zip(xs).map(|(w, x)| w * x * x).sum();
+        let sxy: f64 = ws.iter().zip(xs).zip(ys).map(|((w, x), y)| w * x * y).sum();
+        let delta = s * sxx - sx * sx;
+        let a = (sxx * sy - sx * sxy) / delta;
+        let b = (s * sxy - sx * sy) / delta;
+        assert_float_relative_eq!(fit.coeffs[0], a, 1.0e-12);
+        assert_float_relative_eq!(fit.coeffs[1], b, 1.0e-12);
+        assert_float_relative_eq!(fit.covariance[0], sxx / delta, 1.0e-10);
+        assert_float_relative_eq!(fit.covariance[3], s / delta, 1.0e-10);
+        assert_float_relative_eq!(fit.covariance[1], -sx / delta, 1.0e-10);
+        assert_eq!(fit.covariance[1], fit.covariance[2]);
+        let chi2: f64 = (0..6).map(|i| ws[i] * (ys[i] - a - b * xs[i]).powi(2)).sum();
+        assert_float_relative_eq!(fit.chi_square, chi2, 1.0e-10);
+        assert_float_relative_eq!(fit.std_errors()[1], (s / delta).sqrt(), 1.0e-10);
+
+        // zero weight ignores the outlier
+        let xs = [0.0, 1.0, 2.0, 3.0];
+        let fit = polynomial_fit(&xs, &[1.0, 3.0, 100.0, 7.0], Some(&[1.0, 1.0, 0.0, 1.0]), 1)
+            .expect("fit failed");
+        assert_float_absolute_eq!(fit.coeffs[0], 1.0, 1.0e-13);
+        assert_float_absolute_eq!(fit.coeffs[1], 2.0, 1.0e-13);
+
+        assert_eq!(polynomial_fit(&xs, &[1.0, 2.0], None, 1).unwrap_err(), FitErr::LengthMismatch);
+        assert_eq!(polynomial_fit(&xs, &xs, None, 4).unwrap_err(), FitErr::NotEnoughPoints);
+        assert_eq!(polynomial_fit(&xs, &xs, Some(&[1.0, -1.0, 1.0, 1.0]), 1).unwrap_err(),
+            FitErr::BadWeight);
+        assert_eq!(polynomial_fit(&[1.0, 1.0, 1.0], &[1.0, 2.0, 3.0], None, 1).unwrap_err(),
+            FitErr::RankDeficient);
+    }
+}
@@ -0,0 +1,252 @@
+//! Polynomial interpolation through sample points.
+//!
+//! (c) Igor Lesik 2023
+//! MIT license
+//!
+//! Unique polynomial of degree `n - 1` passes through `n` points `(x_i, y_i)`
+//! with distinct `x_i`. It is built in Newton form with divided differences
+//! and converted to coefficients `c0, c1, c2...` used by [`polynomial_n`](super::polynomial_n),
+//! or evaluated at a point with Lagrange formula or Neville's algorithm.
+//!
+//! High degree interpolation through equally spaced points oscillates (Runge's phenomenon),
+//! monomial coefficients are ill-conditioned for many points,
+//! prefer evaluation with Newton form or Neville's algorithm then.
+//!
+//! References:
+//!
+//! - <https://en.wikipedia.org/wiki/Newton_polynomial>
+//! - <https://en.wikipedia.org/wiki/Lagrange_polynomial>
+//! - <https://en.wikipedia.org/wiki/Neville%27s_algorithm>
+//! - William H. Press - Numerical recipes, 3.2 Polynomial Interpolation and Extrapolation
+
+/// Check sample points and return their number
+fn check_points(xs: &[f64], ys: &[f64]) -> usize {
+    assert_eq!(xs.len(), ys.len(), "number of x and y values must be the same");
+    assert!(!xs.is_empty(), "at least one point is needed");
+    xs.len()
+}
+
+/// Newton divided differences `[y0], [y0,y1], [y0,y1,y2]...`
+///
+/// Interpolating polynomial in Newton form is
+/// `d0 + d1*(x - x0) + d2*(x - x0)*(x - x1) + ...`
+///
+/// # Panics
+///
+/// Panics if `xs` and `ys` lengths differ, or if `x` values are not distinct.
+///
+/// # Example
+///
+/// ```
+/// # use rustamath::polynomial::interpolation::*;
+/// // y = x^2 at 0, 1, 3
+/// let d = divided_differences(&[0.0, 1.0, 3.0], &[0.0, 1.0, 9.0]);
+/// assert_eq!(d, vec![0.0, 1.0, 1.0]);
+/// ```
+pub fn divided_differences(xs: &[f64], ys: &[f64]) -> Vec<f64> {
+    let n = check_points(xs, ys);
+    let mut d = ys.to_vec();
+
+    for k in 1..n {
+        for i in (k..n).rev() {
+            let den = xs[i] - xs[i - k];
+            assert!(den != 0.0, "interpolation points must have distinct x");
+            d[i] = (d[i] - d[i - 1]) / den;
+        }
+    }
+    d
+}
+
+/// Evaluate Newton form with nodes `xs` and divided differences `d` at `x`
+///
+/// # Example
+///
+/// ```
+/// # use rustamath::polynomial::interpolation::*;
+/// let xs = [0.0, 1.0, 3.0];
+/// let d = divided_differences(&xs, &[0.0, 1.0, 9.0]);
+/// assert_eq!(newton_eval(&xs, &d, 2.0), 4.0);
+/// ```
+pub fn newton_eval(xs: &[f64], d: &[f64], x: f64) -> f64 {
+    let n = d.len();
+    let mut res = d[n - 1];
+    for i in (0..n - 1).rev() {
+        res = res * (x - xs[i]) + d[i];
+    }
+    res
+}
+
+/// Convert Newton form to coefficients `c0, c1, c2...`
+///
+/// Horner's scheme with `(x - x_i)` factors, each step multiplies
+/// the coefficients by a linear factor.
+pub fn newton_to_monomial(xs: &[f64], d: &[f64]) -> Vec<f64> {
+    let n = d.len();
+    let mut c = vec![0.0; n];
+    c[0] = d[n - 1];
+
+    for i in (0..n - 1).rev() {
+        // c(x) * (x - x_i) + d_i
+        for j in (1..n - i).rev() {
+            c[j] = c[j - 1] - xs[i] * c[j];
+        }
+        c[0] = d[i] - xs[i] * c[0];
+    }
+    c
+}
+
+/// Coefficients `c0, c1, c2...` of the polynomial passing through points `(x_i, y_i)`
+///
+/// # Panics
+///
+/// Panics if `xs` and `ys` lengths differ, or if `x` values are not distinct.
+///
+/// # Example
+///
+/// ```
+/// # use rustamath::polynomial::{*, interpolation::*};
+/// # use assert_float_eq::*;
+/// let xs = [-1.0, 0.0, 1.0, 2.0];
+/// let ys = xs.map(|x| 1.0 - 2.0*x + x*x*x);
+/// let c = interpolating_polynomial(&xs, &ys);
+/// for (ci, expected) in c.iter().zip([1.0, -2.0, 0.0, 1.0]) {
+///     assert_float_absolute_eq!(*ci, expected, 1.0e-15);
+/// }
+/// assert_float_absolute_eq!(polynomial_n(0.5, &c), 0.125, 1.0e-15);
+/// ```
+pub fn interpolating_polynomial(xs: &[f64], ys: &[f64]) -> Vec<f64> {
+    newton_to_monomial(xs, &divided_differences(xs, ys))
+}
+
+/// Evaluate interpolating polynomial at `x` with Lagrange formula
+///
+/// `P(x) = sum(y_i * prod((x - x_j)/(x_i - x_j), j != i))`
+///
+/// # Panics
+///
+/// Panics if `xs` and `ys` lengths differ, or if `x` values are not distinct.
+///
+/// # Example
+///
+/// ```
+/// # use rustamath::polynomial::interpolation::*;
+/// assert_eq!(lagrange(&[0.0, 1.0, 3.0], &[0.0, 1.0, 9.0], 2.0), 4.0);
+/// ```
+pub fn lagrange(xs: &[f64], ys: &[f64], x: f64) -> f64 {
+    let n = check_points(xs, ys);
+
+    (0..n).map(|i| {
+        let l = (0..n).filter(|&j| j != i).fold(1.0, |l, j| {
+            let den = xs[i] - xs[j];
+            assert!(den != 0.0, "interpolation points must have distinct x");
+            l * (x - xs[j]) / den
+        });
+        ys[i] * l
+    }).sum()
+}
+
+/// Evaluate interpolating polynomial at `x` with Neville's algorithm,
+/// returns `(P(x), error estimate)`
+///
+/// Tableau of interpolants through consecutive points is built from
+/// the nearest point, the error estimate is the last correction added.
+/// This is `polint` from Numerical Recipes.
+///
+/// # Panics
+///
+/// Panics if `xs` and `ys` lengths differ, or if `x` values are not distinct.
+///
+/// # Example
+///
+/// ```
+/// # use rustamath::polynomial::interpolation::*;
+/// # use assert_float_eq::*;
+/// let xs = [0.0, 0.5, 1.0, 1.5, 2.0];
+/// let ys = xs.map(f64::exp);
+/// let (y, dy) = neville(&xs, &ys, 0.8);
+/// assert_float_absolute_eq!(y, 0.8f64.exp(), 1.0e-3);
+/// assert!(dy.abs() < 1.0e-2);
+/// ```
+pub fn neville(xs: &[f64], ys: &[f64], x: f64) -> (f64, f64) {
+    let n = check_points(xs, ys);
+
+    // index of the closest point
+    let mut ns = (0..n)
+        .min_by(|&i, &j| (x - xs[i]).abs().total_cmp(&(x - xs[j]).abs()))
+        .unwrap_or(0);
+
+    let mut c = ys.to_vec();
+    let mut d = ys.to_vec();
+    let mut y = ys[ns];
+    let mut dy = 0.0;
+
+    for m in 1..n {
+        for i in 0..n - m {
+            let ho = xs[i] - x;
+            let hp = xs[i + m] - x;
+            let w = c[i + 1] - d[i];
+            let den = ho - hp;
+            assert!(den != 0.0, "interpolation points must have distinct x");
+            let den = w / den;
+            d[i] = hp * den;
+            c[i] = ho * den;
+        }
+        // take the path through the tableau that stays centered around x
+        dy = if 2 * ns < n - m {
+            c[ns]
+        } else {
+            ns -= 1;
+            d[ns]
+        };
+        y += dy;
+    }
+
+    (y, dy)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::polynomial::polynomial_n;
+
+    #[test]
+    fn interpolation() {
+        let f = |x: f64| 0.5 - 1.5 * x + 0.25 * x * x * x - 0.125 * x * x * x * x;
+        let xs = [-2.0, -0.5, 0.0, 1.0, 1.7, 3.0];
+        let ys = xs.map(f);
+
+        let c = interpolating_polynomial(&xs, &ys);
+        assert_eq!(c.len(), 6);
+        for (ci, expected) in c.iter().zip([0.5, -1.5, 0.0, 0.25, -0.125, 0.0]) {
+            assert_float_absolute_eq!(*ci, expected, 1.0e-13);
+        }
+
+        let d = divided_differences(&xs, &ys);
+        for x in [-1.9, -0.3, 0.2, 0.8, 2.5, 4.0] {
+            assert_float_absolute_eq!(polynomial_n(x, &c), f(x), 1.0e-12);
+            assert_float_absolute_eq!(newton_eval(&xs, &d, x), f(x), 1.0e-12);
+            assert_float_absolute_eq!(lagrange(&xs, &ys, x), f(x), 1.0e-12);
+            let (y, dy) = neville(&xs, &ys, x);
+            assert_float_absolute_eq!(y, f(x), 1.0e-12);
+            // polynomial of degree 4 through 6 points, last correction is zero
+            assert_float_absolute_eq!(dy, 0.0, 1.0e-11);
+        }
+
+        // interpolation passes through the points
+        for (x, y) in xs.iter().zip(ys) {
+            assert_float_absolute_eq!(neville(&xs, &ys, *x).0, y, 1.0e-13);
+            assert_float_absolute_eq!(lagrange(&xs, &ys, *x), y, 1.0e-13);
+        }
+
+        // error estimate follows the real error
+        let xs: Vec<f64> = (0..8).map(|i| i as f64 * 0.25).collect();
+        let ys: Vec<f64> = xs.iter().map(|x| x.sin()).collect();
+        let (y, dy) = neville(&xs, &ys, 0.6);
+        assert!((y - 0.6f64.sin()).abs() <= dy.abs() * 10.0);
+        assert!(dy.abs() < 1.0e-6);
+
+        // single point is a constant
+        assert_eq!(interpolating_polynomial(&[2.0], &[3.0]), vec![3.0]);
+        assert_eq!(neville(&[2.0], &[3.0], 5.0), (3.0, 0.0));
+    }
+}
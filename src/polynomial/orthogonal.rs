@@ -0,0 +1,398 @@
+//! Classical orthogonal polynomials and Gauss quadrature rules.
+//!
+//! (c) Igor Lesik 2023
+//! MIT license
+//!
+//! Each family satisfies three-term recurrence
+//! `p(n+1) = (a_n*x + b_n)*p(n) - c_n*p(n-1)`, `p(0) = 1`, with the standard normalization:
+//!
+//! | Family             | Interval      | Weight                   |
+//! |--------------------|---------------|--------------------------|
+//! | Legendre `P`       | `[-1, 1]`     | `1`                      |
+//! | Chebyshev `T`      | `[-1, 1]`     | `1/sqrt(1 - x^2)`        |
+//! | Chebyshev `U`      | `[-1, 1]`     | `sqrt(1 - x^2)`          |
+//! | Hermite `H`        | `(-inf, inf)` | `exp(-x^2)`              |
+//! | Laguerre `L(a)`    | `[0, inf)`    | `x^a * exp(-x)`          |
+//! | Jacobi `P(a, b)`   | `[-1, 1]`     | `(1 - x)^a * (1 + x)^b`  |
+//!
+//! Roots of `p(n)` and Gauss quadrature weights are eigenvalues and first components
+//! of eigenvectors of the symmetric tridiagonal Jacobi matrix (Golub-Welsch algorithm).
+//!
+//! References:
+//!
+//! - <https://en.wikipedia.org/wiki/Classical_orthogonal_polynomials>
+//! - <https://en.wikipedia.org/wiki/Gaussian_quadrature#The_Golub-Welsch_algorithm>
+//! - G.H. Golub, J.H. Welsch, Calculation of Gauss Quadrature Rules, Math. Comp. 23, 1969
+//! - Abramowitz and Stegun, 22. Orthogonal Polynomials
+//! - William H. Press - Numerical recipes, 4.6 Gaussian Quadratures and Orthogonal Polynomials
+
+use std::f64::consts::PI;
+
+/// Maximum number of QL iterations for one eigenvalue
+const MAX_QL_ITERATIONS: usize = 60;
+
+/// Classical orthogonal polynomial family
+///
+/// # Example
+///
+/// ```
+/// # use rustamath::polynomial::orthogonal::OrthogonalPolynomial;
+/// # use assert_float_eq::*;
+/// let p = OrthogonalPolynomial::Legendre;
+/// assert_float_absolute_eq!(p.eval(3, 0.5), (5.0*0.125 - 3.0*0.5)/2.0, 1.0e-15);
+/// assert_eq!(p.coeffs(2), vec![-0.5, 0.0, 1.5]);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum OrthogonalPolynomial {
+    /// Legendre `P(n)`
+    Legendre,
+    /// Chebyshev of the first kind `T(n)`
+    ChebyshevT,
+    /// Chebyshev of the second kind `U(n)`
+    ChebyshevU,
+    /// Hermite `H(n)`, physicists' version
+    Hermite,
+    /// Generalized Laguerre `L(n, alpha)`, `alpha > -1`
+    Laguerre(f64),
+    /// Jacobi `P(n, alpha, beta)`, `alpha > -1`, `beta > -1`
+    Jacobi(f64, f64),
+}
+
+impl OrthogonalPolynomial {
+    /// Coefficients `(a_n, b_n, c_n)` of recurrence `p(n+1) = (a_n*x + b_n)*p(n) - c_n*p(n-1)`
+    pub fn recurrence(&self, n: usize) -> (f64, f64, f64) {
+        let nf = n as f64;
+        match *self {
+            Self::Legendre => ((2.0*nf + 1.0)/(nf + 1.0), 0.0, nf/(nf + 1.0)),
+            Self::ChebyshevT => (if n == 0 { 1.0 } else { 2.0 }, 0.0, 1.0),
+            Self::ChebyshevU => (2.0, 0.0, 1.0),
+            Self::Hermite => (2.0, 0.0, 2.0*nf),
+            Self::Laguerre(alpha) => (
+                -1.0/(nf + 1.0),
+                (2.0*nf + 1.0 + alpha)/(nf + 1.0),
+                (nf + alpha)/(nf + 1.0),
+            ),
+            Self::Jacobi(alpha, beta) => {
+                if n == 0 {
+                    return (0.5*(alpha + beta + 2.0), 0.5*(alpha - beta), 0.0);
+                }
+                let s = 2.0*nf + alpha + beta;
+                let d = 2.0*(nf + 1.0)*(nf + alpha + beta + 1.0)*s;
+                (
+                    (s + 1.0)*(s + 2.0)*s/d,
+                    (s + 1.0)*(alpha*alpha - beta*beta)/d,
+                    2.0*(nf + alpha)*(nf + beta)*(s + 2.0)/d,
+                )
+            }
+        }
+    }
+
+    /// Value of `p(n)` at `x` with the recurrence
+    pub fn eval(&self, n: usize, x: f64) -> f64 {
+        self.eval_with_derivative(n, x).0
+    }
+
+    /// Value of `p(n)` and its derivative at `x`, `(p, dp/dx)`
+    ///
+    /// Derivative of the recurrence `p'(n+1) = a_n*p(n) + (a_n*x + b_n)*p'(n) - c_n*p'(n-1)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustamath::polynomial::orthogonal::OrthogonalPolynomial;
+    /// // T3 = 4x^3 - 3x
+    /// let (p, dp) = OrthogonalPolynomial::ChebyshevT.eval_with_derivative(3, 0.5);
+    /// assert_eq!((p, dp), (-1.0, 0.0));
+    /// ```
+    pub fn eval_with_derivative(&self, n: usize, x: f64) -> (f64, f64) {
+        let (mut p0, mut dp0) = (0.0, 0.0);
+        let (mut p1, mut dp1) = (1.0, 0.0);
+
+        for k in 0..n {
+            let (a, b, c) = self.recurrence(k);
+            let p2 = (a*x + b)*p1 - c*p0;
+            let dp2 = a*p1 + (a*x + b)*dp1 - c*dp0;
+            (p0, dp0) = (p1, dp1);
+            (p1, dp1) = (p2, dp2);
+        }
+        (p1, dp1)
+    }
+
+    /// Coefficients `c0, c1, c2...` of `p(n)` for [`polynomial_n`](super::polynomial_n)
+    ///
+    /// Derivatives of any order are given by [`derivative_coeffs`](super::derivative_coeffs).
+    /// Monomial form is ill-conditioned for high degree, prefer [`Self::eval`] there.
+    pub fn coeffs(&self, n: usize) -> Vec<f64> {
+        let mut p0 = vec![0.0; n + 1];
+        let mut p1 = vec![0.0; n + 1];
+        p1[0] = 1.0;
+
+        for k in 0..n {
+            let (a, b, c) = self.recurrence(k);
+            let mut p2 = vec![0.0; n + 1];
+            for i in 0..=k + 1 {
+                let shifted = if i > 0 { a*p1[i - 1] } else { 0.0 };
+                p2[i] = shifted + b*p1[i] - c*p0[i];
+            }
+            p0 = p1;
+            p1 = p2;
+        }
+        p1
+    }
+
+    /// Weight function `w(x)` of the orthogonality inner product
+    pub fn weight(&self, x: f64) -> f64 {
+        match *self {
+            Self::Legendre => 1.0,
+            Self::ChebyshevT => 1.0/(1.0 - x*x).sqrt(),
+            Self::ChebyshevU => (1.0 - x*x).sqrt(),
+            Self::Hermite => (-x*x).exp(),
+            Self::Laguerre(alpha) => x.powf(alpha)*(-x).exp(),
+            Self::Jacobi(alpha, beta) => (1.0 - x).powf(alpha)*(1.0 + x).powf(beta),
+        }
+    }
+
+    /// Integral of the weight function over the interval, sum of Gauss quadrature weights
+    pub fn weight_integral(&self) -> f64 {
+        match *self {
+            Self::Legendre => 2.0,
+            Self::ChebyshevT => PI,
+            Self::ChebyshevU => PI/2.0,
+            Self::Hermite => PI.sqrt(),
+            Self::Laguerre(alpha) => ln_gamma(alpha + 1.0).exp(),
+            Self::Jacobi(alpha, beta) => (
+                (alpha + beta + 1.0)*std::f64::consts::LN_2
+                + ln_gamma(alpha + 1.0) + ln_gamma(beta + 1.0) - ln_gamma(alpha + beta + 2.0)
+            ).exp(),
+        }
+    }
+
+    /// Roots of `p(n)` in ascending order and Gauss quadrature weights, Golub-Welsch algorithm
+    ///
+    /// `sum(w_i*f(x_i))` approximates `integral(w(x)*f(x))` over the interval,
+    /// exact for polynomial `f` of degree up to `2n - 1`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustamath::polynomial::orthogonal::OrthogonalPolynomial;
+    /// # use assert_float_eq::*;
+    /// // Gauss-Legendre with 3 points
+    /// let (x, w) = OrthogonalPolynomial::Legendre.roots_and_weights(3);
+    /// assert_float_absolute_eq!(x[0], -0.6f64.sqrt(), 1.0e-15);
+    /// assert_float_absolute_eq!(x[1], 0.0, 1.0e-15);
+    /// assert_float_absolute_eq!(w[0], 5.0/9.0, 1.0e-15);
+    /// assert_float_absolute_eq!(w[1], 8.0/9.0, 1.0e-15);
+    /// // integral of x^4 over [-1, 1]
+    /// let integral: f64 = x.iter().zip(&w).map(|(x, w)| w*x.powi(4)).sum();
+    /// assert_float_absolute_eq!(integral, 0.4, 1.0e-15);
+    /// ```
+    pub fn roots_and_weights(&self, n: usize) -> (Vec<f64>, Vec<f64>) {
+        if n == 0 {
+            return (vec![], vec![]);
+        }
+
+        // symmetric Jacobi matrix of the orthonormal polynomials
+        let mut d = vec![0.0; n];
+        let mut e = vec![0.0; n];
+        let mut a_prev = 0.0;
+        for k in 0..n {
+            let (a, b, c) = self.recurrence(k);
+            d[k] = -b/a;
+            if k > 0 {
+                e[k - 1] = (c/(a_prev*a)).sqrt();
+            }
+            a_prev = a;
+        }
+
+        // first components of eigenvectors
+        let mut z = vec![0.0; n];
+        z[0] = 1.0;
+        tridiagonal_ql(&mut d, &mut e, &mut z);
+
+        let mu0 = self.weight_integral();
+        let mut nodes: Vec<(f64, f64)> = d.into_iter().zip(z).map(|(x, z)| (x, mu0*z*z)).collect();
+        nodes.sort_by(|a, b| a.0.total_cmp(&b.0));
+        nodes.into_iter().unzip()
+    }
+}
+
+/// Eigenvalues of symmetric tridiagonal matrix with implicit QL,
+/// `tqli` from Numerical Recipes tracking only the first row `z` of eigenvectors
+///
+/// `d` diagonal is replaced by eigenvalues, `e[i]` couples `i` and `i+1`, `e` is destroyed.
+fn tridiagonal_ql(d: &mut [f64], e: &mut [f64], z: &mut [f64]) {
+    let n = d.len();
+
+    for l in 0..n {
+        let mut iter = 0;
+        loop {
+            // look for small subdiagonal element to split the matrix
+            let mut m = l;
+            while m + 1 < n {
+                let dd = d[m].abs() + d[m + 1].abs();
+                if e[m].abs() <= f64::EPSILON*dd {
+                    break;
+                }
+                m += 1;
+            }
+            if m == l {
+                break;
+            }
+
+            iter += 1;
+            if iter > MAX_QL_ITERATIONS {
+                break;
+            }
+
+            // Wilkinson shift
+            let mut g = (d[l + 1] - d[l])/(2.0*e[l]);
+            let mut r = g.hypot(1.0);
+            g = d[m] - d[l] + e[l]/(g + r.copysign(g));
+            let (mut s, mut c, mut p) = (1.0, 1.0, 0.0);
+            let mut underflow = false;
+
+            for i in (l..m).rev() {
+                let f = s*e[i];
+                let b = c*e[i];
+                r = f.hypot(g);
+                e[i + 1] = r;
+                if r == 0.0 {
+                    // recover from underflow
+                    d[i + 1] -= p;
+                    e[m] = 0.0;
+                    underflow = true;
+                    break;
+                }
+                s = f/r;
+                c = g/r;
+                g = d[i + 1] - p;
+                r = (d[i] - g)*s + 2.0*c*b;
+                p = s*r;
+                d[i + 1] = g + p;
+                g = c*r - b;
+
+                let f = z[i + 1];
+                z[i + 1] = s*z[i] + c*f;
+                z[i] = c*z[i] - s*f;
+            }
+            if underflow {
+                continue;
+            }
+            d[l] -= p;
+            e[l] = g;
+            e[m] = 0.0;
+        }
+    }
+}
+
+/// Natural logarithm of gamma function for `x > 0`, Lanczos approximation with `g = 7`
+fn ln_gamma(x: f64) -> f64 {
+    const G: f64 = 7.0;
+    const P: [f64; 9] = [
+        0.999_999_999_999_809_9,
+        676.520_368_121_885_1,
+        -1_259.139_216_722_402_8,
+        771.323_428_777_653_1,
+        -176.615_029_162_140_6,
+        12.507_343_278_686_905,
+        -0.138_571_095_265_720_12,
+        9.984_369_578_019_572e-6,
+        1.505_632_735_149_311_6e-7,
+    ];
+
+    if x < 0.5 {
+        // reflection formula
+        return (PI/(PI*x).sin()).ln() - ln_gamma(1.0 - x);
+    }
+
+    let x = x - 1.0;
+    let a = P.iter().enumerate().skip(1).fold(P[0], |a, (i, p)| a + p/(x + i as f64));
+    let t = x + G + 0.5;
+    0.5*(2.0*PI).ln() + (x + 0.5)*t.ln() - t + a.ln()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::polynomial::{polynomial_n, derivative_coeffs};
+    use OrthogonalPolynomial::*;
+
+    #[test]
+    fn orthogonal_polynomials() {
+        // explicit low degree polynomials
+        let check = |p: OrthogonalPolynomial, n: usize, expected: &[f64]| {
+            let c = p.coeffs(n);
+            assert_eq!(c.len(), expected.len());
+            for (a, b) in c.iter().zip(expected) {
+                assert_float_absolute_eq!(*a, *b, 1.0e-14);
+            }
+        };
+        check(Legendre, 3, &[0.0, -1.5, 0.0, 2.5]);
+        check(ChebyshevT, 4, &[1.0, 0.0, -8.0, 0.0, 8.0]);
+        check(ChebyshevU, 3, &[0.0, -4.0, 0.0, 8.0]);
+        check(Hermite, 3, &[0.0, -12.0, 0.0, 8.0]);
+        check(Laguerre(0.0), 2, &[1.0, -2.0, 0.5]);
+        check(Laguerre(1.0), 2, &[3.0, -3.0, 0.5]);
+        check(Jacobi(0.0, 0.0), 4, &Legendre.coeffs(4));
+        check(Jacobi(1.0, 2.0), 1, &[-0.5, 2.5]);
+        // Gegenbauer with lambda = 1 is U, Jacobi(1/2, 1/2) is proportional to it
+        let j = Jacobi(0.5, 0.5).coeffs(3);
+        let u = ChebyshevU.coeffs(3);
+        assert_float_absolute_eq!(j[3]/u[3], j[1]/u[1], 1.0e-14);
+
+        let families = [Legendre, ChebyshevT, ChebyshevU, Hermite, Laguerre(0.0), Laguerre(1.5),
+            Jacobi(0.5, -0.3), Jacobi(-0.5, -0.5), Jacobi(2.0, 1.0)];
+
+        for p in families {
+            // recurrence, coefficients and derivatives agree
+            let n = 7;
+            let c = p.coeffs(n);
+            let dc = derivative_coeffs(&c);
+            for x in [-0.9, -0.3, 0.2, 0.7] {
+                let (y, dy) = p.eval_with_derivative(n, x);
+                assert_float_relative_eq!(y, polynomial_n(x, &c), 1.0e-10);
+                assert_float_relative_eq!(dy, polynomial_n(x, &dc), 1.0e-10);
+            }
+
+            // roots are roots, weights integrate polynomials of degree 2n-1 exactly
+            let (x, w) = p.roots_and_weights(n);
+            assert_eq!(x.len(), n);
+            for xi in x.iter() {
+                let (y, dy) = p.eval_with_derivative(n, *xi);
+                assert!((y/dy).abs() <= 1.0e-13*xi.abs().max(1.0), "{:?} root {}", p, xi);
+            }
+            assert!(x.windows(2).all(|x| x[0] < x[1]));
+            assert!(w.iter().all(|w| *w > 0.0));
+            assert_float_relative_eq!(w.iter().sum::<f64>(), p.weight_integral(), 1.0e-13);
+
+            // orthogonality with the quadrature
+            for (i, j) in [(1, 2), (2, 5), (3, 6), (0, 4)] {
+                let dot: f64 = x.iter().zip(&w).map(|(x, w)| w*p.eval(i, *x)*p.eval(j, *x)).sum();
+                let norm: f64 = x.iter().zip(&w).map(|(x, w)| w*p.eval(j, *x).powi(2)).sum();
+                assert!(dot.abs() <= 1.0e-12*norm, "{:?} <{}, {}> = {}", p, i, j, dot);
+            }
+        }
+
+        // known integrals
+        let quad = |p: OrthogonalPolynomial, n: usize, f: &dyn Fn(f64) -> f64| {
+            let (x, w) = p.roots_and_weights(n);
+            x.iter().zip(w).map(|(x, w)| w*f(*x)).sum::<f64>()
+        };
+        assert_float_relative_eq!(quad(Legendre, 5, &|x| x.powi(8)), 2.0/9.0, 1.0e-14);
+        assert_float_relative_eq!(quad(ChebyshevT, 2, &|x| x*x), PI/2.0, 1.0e-14);
+        assert_float_relative_eq!(quad(Hermite, 4, &|x| x*x), PI.sqrt()/2.0, 1.0e-14);
+        assert_float_relative_eq!(quad(Laguerre(0.0), 3, &|x| x.powi(3)), 6.0, 1.0e-13);
+        assert_float_relative_eq!(quad(Jacobi(1.0, 2.0), 3, &|_| 1.0), 4.0/3.0, 1.0e-14);
+        // Chebyshev nodes
+        let (x, w) = ChebyshevT.roots_and_weights(6);
+        for (k, (xk, wk)) in x.iter().zip(w).enumerate() {
+            assert_float_absolute_eq!(*xk, -((2*k + 1) as f64*PI/12.0).cos(), 1.0e-15);
+            assert_float_absolute_eq!(wk, PI/6.0, 1.0e-14);
+        }
+
+        assert_float_absolute_eq!(ln_gamma(5.0), 24.0f64.ln(), 1.0e-14);
+        assert_float_absolute_eq!(ln_gamma(0.5), PI.sqrt().ln(), 1.0e-14);
+        assert!(Legendre.roots_and_weights(0).0.is_empty());
+    }
+}
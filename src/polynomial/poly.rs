@@ -0,0 +1,298 @@
+//! Polynomial as a value with arithmetic operators.
+//!
+//! (c) Igor Lesik 2023
+//! MIT license
+//!
+//! Coefficients are stored from the constant term up, `c0 + c1*x + c2*x^2 + ...`,
+//! same as slices taken by [`polynomial_n`](super::polynomial_n).
+
+use std::fmt;
+use std::ops::{Add, Sub, Mul, Neg};
+use num_traits::{Zero, One};
+
+/// Polynomial `c0 + c1*x + c2*x^2 + ...`
+///
+/// Trailing (highest degree) zero coefficients are removed,
+/// zero polynomial is kept as single zero coefficient.
+///
+/// # Example
+///
+/// ```
+/// # use rustamath::polynomial::Polynomial;
+/// let p = Polynomial::new(vec![-1.0, 1.0]);        // x - 1
+/// let q = Polynomial::new(vec![1.0, 1.0]);         // x + 1
+/// let r = &p * &q;                                 // x^2 - 1
+/// assert_eq!(r.coeffs(), &[-1.0, 0.0, 1.0]);
+/// assert_eq!(r.degree(), 2);
+/// assert_eq!(r.eval(3.0), 8.0);
+/// assert_eq!(format!("{}", r), "-1*x^0 + 0*x^1 + 1*x^2");
+/// assert_eq!((r - p * q).is_zero(), true);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Polynomial<T = f64> {
+    coeffs: Vec<T>,
+}
+
+impl<T: Zero + Clone> Polynomial<T> {
+    /// Create polynomial from coefficients `c0, c1, c2...`
+    pub fn new(coeffs: Vec<T>) -> Polynomial<T> {
+        let mut p = Polynomial {coeffs};
+        p.normalize();
+        p
+    }
+
+    /// Zero polynomial
+    pub fn zero() -> Polynomial<T> {
+        Polynomial {coeffs: vec![T::zero()]}
+    }
+
+    /// Constant polynomial
+    pub fn constant(c: T) -> Polynomial<T> {
+        Polynomial::new(vec![c])
+    }
+
+    /// Coefficients `c0, c1, c2...`
+    pub fn coeffs(&self) -> &[T] {
+        &self.coeffs
+    }
+
+    /// Take coefficients out of the polynomial
+    pub fn into_coeffs(self) -> Vec<T> {
+        self.coeffs
+    }
+
+    /// Degree of the polynomial, zero polynomial has degree 0
+    pub fn degree(&self) -> usize {
+        self.coeffs.len() - 1
+    }
+
+    /// Coefficient of the highest degree term
+    pub fn leading_coeff(&self) -> T {
+        self.coeffs[self.coeffs.len() - 1].clone()
+    }
+
+    /// Return true if all coefficients are zero
+    pub fn is_zero(&self) -> bool {
+        self.coeffs.len() == 1 && self.coeffs[0].is_zero()
+    }
+
+    /// Remove trailing zero coefficients
+    pub fn normalize(&mut self) {
+        while self.coeffs.len() > 1 && self.coeffs[self.coeffs.len() - 1].is_zero() {
+            self.coeffs.pop();
+        }
+        if self.coeffs.is_empty() {
+            self.coeffs.push(T::zero());
+        }
+    }
+}
+
+impl<T: One + Zero + Clone> Polynomial<T> {
+    /// Monomial `x^n`
+    pub fn monomial(n: usize) -> Polynomial<T> {
+        let mut coeffs = vec![T::zero(); n + 1];
+        coeffs[n] = T::one();
+        Polynomial {coeffs}
+    }
+
+    /// Derivative `c1 + 2*c2*x + 3*c3*x^2 + ...`
+    pub fn derivative(&self) -> Polynomial<T> {
+        let mut k = T::zero();
+        let coeffs = self.coeffs.iter().skip(1).map(|c| {
+            k = k.clone() + T::one();
+            c.clone() * k.clone()
+        }).collect();
+        Polynomial::new(coeffs)
+    }
+}
+
+impl Polynomial<f64> {
+    /// Evaluate polynomial with Horner's method, see [`polynomial_n`](super::polynomial_n)
+    pub fn eval(&self, x: f64) -> f64 {
+        super::polynomial_n(x, &self.coeffs)
+    }
+
+    /// Antiderivative with constant term `k`, see [`integral_coeffs`](super::integral_coeffs)
+    pub fn integral(&self, k: f64) -> Polynomial<f64> {
+        Polynomial::new(super::integral_coeffs(&self.coeffs, k))
+    }
+}
+
+impl<T: Zero + Clone> From<Vec<T>> for Polynomial<T> {
+    fn from(coeffs: Vec<T>) -> Polynomial<T> {
+        Polynomial::new(coeffs)
+    }
+}
+
+/// Same format as `c0*x^0 + c1*x^1 + c2*x^2`
+impl<T: fmt::Display> fmt::Display for Polynomial<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, c) in self.coeffs.iter().enumerate() {
+            if i > 0 {
+                write!(f, " + ")?;
+            }
+            write!(f, "{c}*x^{i}")?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a, T: Zero + Clone> Add<&'a Polynomial<T>> for &'a Polynomial<T> {
+    type Output = Polynomial<T>;
+
+    fn add(self, other: &Polynomial<T>) -> Polynomial<T> {
+        let n = self.coeffs.len().max(other.coeffs.len());
+        let coeffs = (0..n).map(|i| {
+            let a = self.coeffs.get(i).cloned().unwrap_or_else(T::zero);
+            let b = other.coeffs.get(i).cloned().unwrap_or_else(T::zero);
+            a + b
+        }).collect();
+        Polynomial::new(coeffs)
+    }
+}
+
+impl<'a, T: Zero + Clone + Sub<Output = T>> Sub<&'a Polynomial<T>> for &'a Polynomial<T> {
+    type Output = Polynomial<T>;
+
+    fn sub(self, other: &Polynomial<T>) -> Polynomial<T> {
+        let n = self.coeffs.len().max(other.coeffs.len());
+        let coeffs = (0..n).map(|i| {
+            let a = self.coeffs.get(i).cloned().unwrap_or_else(T::zero);
+            let b = other.coeffs.get(i).cloned().unwrap_or_else(T::zero);
+            a - b
+        }).collect();
+        Polynomial::new(coeffs)
+    }
+}
+
+impl<'a, T: Zero + Clone + Mul<Output = T>> Mul<&'a Polynomial<T>> for &'a Polynomial<T> {
+    type Output = Polynomial<T>;
+
+    fn mul(self, other: &Polynomial<T>) -> Polynomial<T> {
+        let mut coeffs = vec![T::zero(); self.coeffs.len() + other.coeffs.len() - 1];
+        for (i, a) in self.coeffs.iter().enumerate() {
+            for (j, b) in other.coeffs.iter().enumerate() {
+                coeffs[i + j] = coeffs[i + j].clone() + a.clone() * b.clone();
+            }
+        }
+        Polynomial::new(coeffs)
+    }
+}
+
+impl<T: Zero + Clone + Mul<Output = T>> Mul<T> for &Polynomial<T> {
+    type Output = Polynomial<T>;
+
+    fn mul(self, k: T) -> Polynomial<T> {
+        Polynomial::new(self.coeffs.iter().map(|c| c.clone() * k.clone()).collect())
+    }
+}
+
+impl<T: Zero + Clone + Neg<Output = T>> Neg for &Polynomial<T> {
+    type Output = Polynomial<T>;
+
+    fn neg(self) -> Polynomial<T> {
+        Polynomial {coeffs: self.coeffs.iter().map(|c| -c.clone()).collect()}
+    }
+}
+
+impl<T: Zero + Clone> Add for Polynomial<T> {
+    type Output = Polynomial<T>;
+
+    fn add(self, other: Polynomial<T>) -> Polynomial<T> {
+        &self + &other
+    }
+}
+
+impl<T: Zero + Clone + Sub<Output = T>> Sub for Polynomial<T> {
+    type Output = Polynomial<T>;
+
+    fn sub(self, other: Polynomial<T>) -> Polynomial<T> {
+        &self - &other
+    }
+}
+
+impl<T: Zero + Clone + Mul<Output = T>> Mul for Polynomial<T> {
+    type Output = Polynomial<T>;
+
+    fn mul(self, other: Polynomial<T>) -> Polynomial<T> {
+        &self * &other
+    }
+}
+
+impl<T: Zero + Clone + Mul<Output = T>> Mul<T> for Polynomial<T> {
+    type Output = Polynomial<T>;
+
+    fn mul(self, k: T) -> Polynomial<T> {
+        &self * k
+    }
+}
+
+impl<T: Zero + Clone + Neg<Output = T>> Neg for Polynomial<T> {
+    type Output = Polynomial<T>;
+
+    fn neg(self) -> Polynomial<T> {
+        -&self
+    }
+}
+
+/// Scalar on the left `k * P(x)`
+impl Mul<Polynomial<f64>> for f64 {
+    type Output = Polynomial<f64>;
+
+    fn mul(self, p: Polynomial<f64>) -> Polynomial<f64> {
+        p * self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_complex::Complex;
+
+    #[test]
+    fn arithmetic() {
+        let p = Polynomial::new(vec![1.0, 2.0, 3.0]);
+        let q = Polynomial::new(vec![4.0, 5.0]);
+
+        assert_eq!((&p + &q).coeffs(), &[5.0, 7.0, 3.0]);
+        assert_eq!((&p - &q).coeffs(), &[-3.0, -3.0, 3.0]);
+        assert_eq!((&q - &p).coeffs(), &[3.0, 3.0, -3.0]);
+        assert_eq!((&p * &q).coeffs(), &[4.0, 13.0, 22.0, 15.0]);
+        assert_eq!((-&q).coeffs(), &[-4.0, -5.0]);
+        assert_eq!((&p * 2.0).coeffs(), &[2.0, 4.0, 6.0]);
+        assert_eq!(2.0 * p.clone(), p.clone() * 2.0);
+
+        // cancellation of the highest degree terms
+        let r = &p - &Polynomial::new(vec![0.0, 0.0, 3.0]);
+        assert_eq!(r.degree(), 1);
+        assert_eq!(r.leading_coeff(), 2.0);
+        assert!((&p - &p).is_zero());
+        assert_eq!((&p * 0.0), Polynomial::zero());
+        assert_eq!((&p * &Polynomial::zero()).degree(), 0);
+
+        let x = 0.12345678;
+        assert_f64_near!((&p * &q).eval(x), p.eval(x) * q.eval(x));
+        assert_eq!(Polynomial::<f64>::monomial(3).eval(2.0), 8.0);
+        assert_eq!(p.derivative().coeffs(), &[2.0, 6.0]);
+        assert!(Polynomial::constant(5.0).derivative().is_zero());
+        assert_eq!(p.integral(1.0).coeffs(), &[1.0, 1.0, 1.0, 1.0]);
+        assert_eq!(p.integral(0.0).derivative(), p);
+    }
+
+    #[test]
+    fn normalization_and_display() {
+        let p = Polynomial::new(vec![1.5, 0.0, -2.0, 0.0, 0.0]);
+        assert_eq!(p.degree(), 2);
+        assert_eq!(p.coeffs(), &[1.5, 0.0, -2.0]);
+        assert_eq!(format!("{}", p), "1.5*x^0 + 0*x^1 + -2*x^2");
+        assert_eq!(Polynomial::<f64>::new(vec![]), Polynomial::zero());
+        assert_eq!(format!("{}", Polynomial::<f64>::zero()), "0*x^0");
+
+        // complex coefficients
+        let i = Complex::new(0.0, 1.0);
+        let p = Polynomial::new(vec![i, Complex::new(1.0, 0.0)]);  // x + i
+        let q = Polynomial::new(vec![-i, Complex::new(1.0, 0.0)]); // x - i
+        assert_eq!((p * q).coeffs(), &[Complex::new(1.0, 0.0), Complex::new(0.0, 0.0),
+            Complex::new(1.0, 0.0)]);
+    }
+}
@@ -0,0 +1,524 @@
+//! Rational functions and Padé approximants.
+//!
+//! (c) Igor Lesik 2023
+//! MIT license
+//!
+//! Rational function `P(x)/Q(x)` is kept as two [`Polynomial`]s,
+//! arithmetic does not cancel common factors, use [`RationalFunction::reduce`] for that.
+//! Transfer function `H(s)` of a linear system is a rational function,
+//! its frequency response is [`RationalFunction::eval_complex`] at `s = i*w`.
+//!
+//! References:
+//!
+//! - <https://en.wikipedia.org/wiki/Rational_function>
+//! - <https://en.wikipedia.org/wiki/Partial_fraction_decomposition>
+//! - <https://en.wikipedia.org/wiki/Pad%C3%A9_approximant>
+//! - William H. Press - Numerical recipes, 5.12 Padé Approximants
+
+use std::ops::{Add, Sub, Mul, Div, Neg};
+use num_complex::Complex;
+use num_traits::Num;
+use crate::la::gauss::solve_linear;
+use super::Polynomial;
+
+/// Rational function error
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RationalErr {
+    /// Number of roots with multiplicities is not the degree of the denominator
+    RootsMismatch,
+    /// Root of the denominator is repeated or is a root of another factor
+    RepeatedRoot,
+    /// Fewer than `L + M + 1` Taylor coefficients
+    NotEnoughTerms,
+    /// Linear system for the denominator is singular, `[L/M]` approximant does not exist
+    Singular,
+}
+
+/// Rational function `P(x)/Q(x)`
+///
+/// # Example
+///
+/// ```
+/// # use rustamath::polynomial::{Polynomial, rational::RationalFunction};
+/// // 1/(x + 1) + 1/(x - 1) = 2x/(x^2 - 1)
+/// let f = RationalFunction::new(Polynomial::constant(1.0), Polynomial::new(vec![1.0, 1.0]));
+/// let g = RationalFunction::new(Polynomial::constant(1.0), Polynomial::new(vec![-1.0, 1.0]));
+/// let h = &f + &g;
+/// assert_eq!(h.numerator().coeffs(), &[0.0, 2.0]);
+/// assert_eq!(h.denominator().coeffs(), &[-1.0, 0.0, 1.0]);
+/// assert_eq!(h.eval(3.0), 0.75);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct RationalFunction<T = f64> {
+    num: Polynomial<T>,
+    den: Polynomial<T>,
+}
+
+impl<T: Num + Clone> RationalFunction<T> {
+    /// Create rational function `num/den`
+    ///
+    /// # Panics
+    ///
+    /// Panics if the denominator is zero polynomial.
+    pub fn new(num: Polynomial<T>, den: Polynomial<T>) -> RationalFunction<T> {
+        assert!(!den.is_zero(), "denominator is zero polynomial");
+        RationalFunction {num, den}
+    }
+
+    /// Numerator `P(x)`
+    pub fn numerator(&self) -> &Polynomial<T> {
+        &self.num
+    }
+
+    /// Denominator `Q(x)`
+    pub fn denominator(&self) -> &Polynomial<T> {
+        &self.den
+    }
+
+    /// Reciprocal `Q(x)/P(x)`
+    ///
+    /// # Panics
+    ///
+    /// Panics if the numerator is zero polynomial.
+    pub fn recip(&self) -> RationalFunction<T> {
+        RationalFunction::new(self.den.clone(), self.num.clone())
+    }
+}
+
+impl<T: Num + Clone> From<Polynomial<T>> for RationalFunction<T> {
+    fn from(p: Polynomial<T>) -> RationalFunction<T> {
+        RationalFunction {num: p, den: Polynomial::constant(T::one())}
+    }
+}
+
+/// Polynomial with real coefficients at complex `z`
+fn horner_complex(cs: &[f64], z: Complex<f64>) -> Complex<f64> {
+    cs.iter().rev().fold(Complex::new(0.0, 0.0), |acc, c| acc * z + c)
+}
+
+/// First `m` Taylor coefficients of polynomial `cs` at `r`, repeated synthetic division
+fn taylor_at(cs: &[Complex<f64>], r: Complex<f64>, m: usize) -> Vec<Complex<f64>> {
+    let mut q = cs.to_vec();
+    let mut t = Vec::with_capacity(m);
+    for _ in 0..m {
+        let mut acc = Complex::new(0.0, 0.0);
+        for c in q.iter_mut().rev() {
+            acc = acc * r + *c;
+            *c = acc;
+        }
+        // q[0] is the remainder, the rest is the quotient
+        t.push(q.remove(0));
+        if q.is_empty() {
+            q.push(Complex::new(0.0, 0.0));
+        }
+    }
+    t
+}
+
+/// Partial fraction decomposition
+/// `P(x)/Q(x) = polynomial(x) + sum(coeff/(x - root)^power)`
+#[derive(Clone, Debug)]
+pub struct PartialFractions {
+    /// Polynomial part, quotient of `P/Q`
+    pub polynomial: Polynomial<f64>,
+    /// Terms `(root, power, coeff)` of `coeff/(x - root)^power`
+    pub terms: Vec<(Complex<f64>, usize, Complex<f64>)>,
+}
+
+impl PartialFractions {
+    /// Evaluate decomposition at complex `z`
+    pub fn eval(&self, z: Complex<f64>) -> Complex<f64> {
+        self.terms.iter().fold(horner_complex(self.polynomial.coeffs(), z), |acc, (r, k, c)| {
+            acc + c / (z - r).powu(*k as u32)
+        })
+    }
+}
+
+impl RationalFunction<f64> {
+    /// Evaluate `P(x)/Q(x)`
+    pub fn eval(&self, x: f64) -> f64 {
+        self.num.eval(x) / self.den.eval(x)
+    }
+
+    /// Evaluate `P(z)/Q(z)` at complex `z`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustamath::polynomial::{Polynomial, rational::RationalFunction};
+    /// # use num_complex::Complex;
+    /// # use assert_float_eq::*;
+    /// // low-pass filter H(s) = 1/(s + 1), gain at cutoff frequency is 1/sqrt(2)
+    /// let h = RationalFunction::new(Polynomial::constant(1.0), Polynomial::new(vec![1.0, 1.0]));
+    /// let g = h.eval_complex(Complex::new(0.0, 1.0));
+    /// assert_float_absolute_eq!(g.norm(), 0.5f64.sqrt(), 1.0e-15);
+    /// assert_float_absolute_eq!(g.arg(), -std::f64::consts::FRAC_PI_4, 1.0e-15);
+    /// ```
+    pub fn eval_complex(&self, z: Complex<f64>) -> Complex<f64> {
+        horner_complex(self.num.coeffs(), z) / horner_complex(self.den.coeffs(), z)
+    }
+
+    /// Derivative `(P'Q - PQ')/Q^2`
+    pub fn derivative(&self) -> RationalFunction<f64> {
+        let num = &(&self.num.derivative() * &self.den) - &(&self.num * &self.den.derivative());
+        RationalFunction::new(num, &self.den * &self.den)
+    }
+
+    /// Cancel common factor of numerator and denominator,
+    /// `tolerance` is passed to [`Polynomial::gcd`]
+    ///
+    /// Denominator is made monic.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustamath::polynomial::{Polynomial, rational::RationalFunction};
+    /// // (x^2 - 1)/(2x - 2) = (x + 1)/2
+    /// let f = RationalFunction::new(Polynomial::new(vec![-1.0, 0.0, 1.0]),
+    ///     Polynomial::new(vec![-2.0, 2.0]));
+    /// let g = f.reduce(1.0e-12);
+    /// assert_eq!(g.numerator().coeffs(), &[0.5, 0.5]);
+    /// assert_eq!(g.denominator().coeffs(), &[1.0]);
+    /// ```
+    pub fn reduce(&self, tolerance: f64) -> RationalFunction<f64> {
+        let g = self.num.gcd(&self.den, tolerance);
+        let (num, den) = if g.degree() > 0 && !self.num.is_zero() {
+            (&self.num / &g, &self.den / &g)
+        } else {
+            (self.num.clone(), self.den.clone())
+        };
+        let lead = den.leading_coeff();
+        RationalFunction::new(&num * (1.0 / lead), &den * (1.0 / lead))
+    }
+
+    /// Partial fraction decomposition given distinct roots of the denominator
+    /// with their multiplicities
+    ///
+    /// Root `r` of multiplicity `m` gives terms with powers `1..=m`,
+    /// coefficient of `1/(x - r)^(m - k)` is `k`-th Taylor coefficient at `r`
+    /// of `(x - r)^m * P(x)/Q(x)`. Complex conjugate roots give conjugate coefficients.
+    /// Accuracy of the coefficients follows accuracy of the roots.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustamath::polynomial::{Polynomial, rational::RationalFunction};
+    /// # use num_complex::Complex;
+    /// # use assert_float_eq::*;
+    /// // (x + 3)/((x - 1)(x + 1)) = 2/(x - 1) - 1/(x + 1)
+    /// let f = RationalFunction::new(Polynomial::new(vec![3.0, 1.0]),
+    ///     Polynomial::new(vec![-1.0, 0.0, 1.0]));
+    /// let pf = f.partial_fractions(&[(Complex::new(1.0, 0.0), 1), (Complex::new(-1.0, 0.0), 1)])
+    ///     .expect("partial fractions failed");
+    /// assert!(pf.polynomial.is_zero());
+    /// assert_float_absolute_eq!(pf.terms[0].2.re, 2.0, 1.0e-15);
+    /// assert_float_absolute_eq!(pf.terms[1].2.re, -1.0, 1.0e-15);
+    /// ```
+    pub fn partial_fractions(
+        &self,
+        roots: &[(Complex<f64>, usize)],
+    ) -> Result<PartialFractions, RationalErr> {
+        let n: usize = roots.iter().map(|(_, m)| m).sum();
+        if n != self.den.degree() || roots.iter().any(|(_, m)| *m == 0) {
+            return Err(RationalErr::RootsMismatch);
+        }
+
+        let (polynomial, rem) = self.num.div_rem(&self.den);
+        let rem: Vec<Complex<f64>> = rem.coeffs().iter().map(|c| Complex::new(*c, 0.0)).collect();
+        let lead = Complex::new(self.den.leading_coeff(), 0.0);
+
+        let mut terms = Vec::with_capacity(n);
+        for (i, (r, m)) in roots.iter().enumerate() {
+            // Q(x) = (x - r)^m * h(x)
+            let h = roots.iter().enumerate()
+                .filter(|(j, _)| *j != i)
+                .fold(Polynomial::constant(lead), |h, (_, (rj, mj))| {
+                    (0..*mj).fold(h, |h, _| h * Polynomial::new(vec![-rj, Complex::new(1.0, 0.0)]))
+                });
+
+            let nt = taylor_at(&rem, *r, *m);
+            let ht = taylor_at(h.coeffs(), *r, *m);
+            if ht[0].norm() == 0.0 {
+                return Err(RationalErr::RepeatedRoot);
+            }
+
+            // Taylor coefficients of remainder/h, power series division
+            let mut g: Vec<Complex<f64>> = Vec::with_capacity(*m);
+            for k in 0..*m {
+                let s = (1..=k).fold(nt[k], |s, j| s - ht[j] * g[k - j]);
+                g.push(s / ht[0]);
+            }
+
+            for (k, gk) in g.iter().enumerate().rev() {
+                terms.push((*r, m - k, *gk));
+            }
+        }
+
+        Ok(PartialFractions {polynomial, terms})
+    }
+
+    /// `[L/M]` Padé approximant from Taylor coefficients `c0, c1, ...` of a function at zero
+    ///
+    /// Numerator has degree `l`, denominator has degree `m` and constant term 1,
+    /// Taylor expansion of `P/Q` matches the first `l + m + 1` coefficients.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustamath::polynomial::rational::RationalFunction;
+    /// # use assert_float_eq::*;
+    /// // exp(x) = 1 + x + x^2/2 + x^3/6 + x^4/24 + ...
+    /// let c = [1.0, 1.0, 1.0/2.0, 1.0/6.0, 1.0/24.0];
+    /// let f = RationalFunction::pade(&c, 2, 2).expect("Pade failed");
+    /// // (1 + x/2 + x^2/12)/(1 - x/2 + x^2/12)
+    /// assert_float_absolute_eq!(f.denominator().coeffs()[1], -0.5, 1.0e-15);
+    /// assert_float_absolute_eq!(f.eval(0.5), 0.5f64.exp(), 1.0e-4);
+    /// ```
+    pub fn pade(taylor: &[f64], l: usize, m: usize) -> Result<RationalFunction<f64>, RationalErr> {
+        if taylor.len() < l + m + 1 {
+            return Err(RationalErr::NotEnoughTerms);
+        }
+
+        let c = |k: isize| if k < 0 { 0.0 } else { taylor[k as usize] };
+        let l = l as isize;
+
+        // sum(q_j * c_(l+i-j), j = 1..m) = -c_(l+i), i = 1..m
+        let mut a = vec![0.0; m * m];
+        let mut b = vec![0.0; m];
+        for i in 0..m {
+            for j in 0..m {
+                a[i*m + j] = c(l + i as isize - j as isize);
+            }
+            b[i] = -c(l + i as isize + 1);
+        }
+        let q = solve_linear(&mut a, &mut b, m).ok_or(RationalErr::Singular)?;
+
+        let mut den = vec![1.0];
+        den.extend(q);
+        let num = (0..=l).map(|i| {
+            (0..=m.min(i as usize)).map(|j| den[j] * c(i - j as isize)).sum()
+        }).collect();
+
+        Ok(RationalFunction::new(Polynomial::new(num), Polynomial::new(den)))
+    }
+}
+
+impl<'a, T: Num + Clone> Add<&'a RationalFunction<T>> for &'a RationalFunction<T> {
+    type Output = RationalFunction<T>;
+
+    fn add(self, other: &RationalFunction<T>) -> RationalFunction<T> {
+        if self.den == other.den {
+            return RationalFunction::new(&self.num + &other.num, self.den.clone());
+        }
+        RationalFunction::new(&(&self.num * &other.den) + &(&other.num * &self.den),
+            &self.den * &other.den)
+    }
+}
+
+impl<'a, T: Num + Clone> Sub<&'a RationalFunction<T>> for &'a RationalFunction<T> {
+    type Output = RationalFunction<T>;
+
+    fn sub(self, other: &RationalFunction<T>) -> RationalFunction<T> {
+        if self.den == other.den {
+            return RationalFunction::new(&self.num - &other.num, self.den.clone());
+        }
+        RationalFunction::new(&(&self.num * &other.den) - &(&other.num * &self.den),
+            &self.den * &other.den)
+    }
+}
+
+impl<'a, T: Num + Clone> Mul<&'a RationalFunction<T>> for &'a RationalFunction<T> {
+    type Output = RationalFunction<T>;
+
+    fn mul(self, other: &RationalFunction<T>) -> RationalFunction<T> {
+        RationalFunction::new(&self.num * &other.num, &self.den * &other.den)
+    }
+}
+
+/// # Panics
+///
+/// Panics if the divisor is zero.
+impl<'a, T: Num + Clone> Div<&'a RationalFunction<T>> for &'a RationalFunction<T> {
+    type Output = RationalFunction<T>;
+
+    fn div(self, other: &RationalFunction<T>) -> RationalFunction<T> {
+        RationalFunction::new(&self.num * &other.den, &self.den * &other.num)
+    }
+}
+
+impl<T: Num + Clone + Neg<Output = T>> Neg for &RationalFunction<T> {
+    type Output = RationalFunction<T>;
+
+    fn neg(self) -> RationalFunction<T> {
+        RationalFunction {num: -&self.num, den: self.den.clone()}
+    }
+}
+
+impl<T: Num + Clone> Add for RationalFunction<T> {
+    type Output = RationalFunction<T>;
+
+    fn add(self, other: RationalFunction<T>) -> RationalFunction<T> {
+        &self + &other
+    }
+}
+
+impl<T: Num + Clone> Sub for RationalFunction<T> {
+    type Output = RationalFunction<T>;
+
+    fn sub(self, other: RationalFunction<T>) -> RationalFunction<T> {
+        &self - &other
+    }
+}
+
+impl<T: Num + Clone> Mul for RationalFunction<T> {
+    type Output = RationalFunction<T>;
+
+    fn mul(self, other: RationalFunction<T>) -> RationalFunction<T> {
+        &self * &other
+    }
+}
+
+impl<T: Num + Clone> Div for RationalFunction<T> {
+    type Output = RationalFunction<T>;
+
+    fn div(self, other: RationalFunction<T>) -> RationalFunction<T> {
+        &self / &other
+    }
+}
+
+impl<T: Num + Clone + Neg<Output = T>> Neg for RationalFunction<T> {
+    type Output = RationalFunction<T>;
+
+    fn neg(self) -> RationalFunction<T> {
+        -&self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn poly(c: &[f64]) -> Polynomial {
+        Polynomial::new(c.to_vec())
+    }
+
+    #[test]
+    fn arithmetic() {
+        let f = RationalFunction::new(poly(&[1.0, 2.0]), poly(&[3.0, 0.0, 1.0]));
+        let g = RationalFunction::new(poly(&[-1.0, 0.5, 1.0]), poly(&[2.0, -1.0]));
+
+        for x in [-1.5, 0.0, 0.7, 3.0] {
+            let (fx, gx) = (f.eval(x), g.eval(x));
+            assert_float_relative_eq!((&f + &g).eval(x), fx + gx, 1.0e-14);
+            assert_float_relative_eq!((&f - &g).eval(x), fx - gx, 1.0e-14);
+            assert_float_relative_eq!((&f * &g).eval(x), fx * gx, 1.0e-14);
+            assert_float_relative_eq!((&f / &g).eval(x), fx / gx, 1.0e-14);
+            assert_float_relative_eq!((-&f).eval(x), -fx, 1.0e-15);
+            assert_float_relative_eq!(f.recip().eval(x), 1.0 / fx, 1.0e-15);
+
+            let h = 1.0e-6;
+            let df = (f.eval(x + h) - f.eval(x - h)) / (2.0 * h);
+            assert_float_absolute_eq!(f.derivative().eval(x), df, 1.0e-8);
+
+            let z = Complex::new(x, 0.0);
+            assert_float_relative_eq!(f.eval_complex(z).re, fx, 1.0e-15);
+        }
+
+        // same denominator, no degree growth
+        let s = f.clone() + f.clone();
+        assert_eq!(s.denominator(), f.denominator());
+        assert_eq!(s.numerator().coeffs(), &[2.0, 4.0]);
+        assert!((&f - &f).numerator().is_zero());
+
+        let p: RationalFunction = poly(&[1.0, 1.0]).into();
+        assert_eq!(p.eval(2.0), 3.0);
+        assert_eq!((p.clone() * f.clone()).denominator(), f.denominator());
+
+        // f*g/g reduces back to f with monic denominator
+        let r = (&(&f * &g) / &g).reduce(1.0e-12);
+        assert_eq!(r.denominator().degree(), 2);
+        for (a, b) in r.numerator().coeffs().iter().zip([1.0, 2.0]) {
+            assert_float_absolute_eq!(*a, b, 1.0e-12);
+        }
+        for (a, b) in r.denominator().coeffs().iter().zip([3.0, 0.0, 1.0]) {
+            assert_float_absolute_eq!(*a, b, 1.0e-12);
+        }
+    }
+
+    #[test]
+    fn partial_fractions() {
+        let re = |x: f64| Complex::new(x, 0.0);
+
+        // (x^4 + 1)/((x - 1)^2 (x + 2)) = x + (10/9)/(x - 1) + (2/3)/(x - 1)^2 + (17/9)/(x + 2)
+        let f = RationalFunction::new(poly(&[1.0, 0.0, 0.0, 0.0, 1.0]), poly(&[2.0, -3.0, 0.0, 1.0]));
+        let pf = f.partial_fractions(&[(re(1.0), 2), (re(-2.0), 1)]).expect("partial fractions failed");
+        assert_eq!(pf.polynomial.coeffs(), &[0.0, 1.0]);
+        assert_eq!(pf.terms.len(), 3);
+        let expected = [(1.0, 1, 10.0/9.0), (1.0, 2, 2.0/3.0), (-2.0, 1, 17.0/9.0)];
+        for ((r, k, c), (er, ek, ec)) in pf.terms.iter().zip(expected) {
+            assert_eq!((r.re, *k), (er, ek));
+            assert_float_absolute_eq!(c.re, ec, 1.0e-14);
+            assert_float_absolute_eq!(c.im, 0.0, 1.0e-14);
+        }
+        for x in [-1.0, 0.5, 3.0] {
+            assert_float_relative_eq!(pf.eval(re(x)).re, f.eval(x), 1.0e-13);
+        }
+
+        // complex conjugate poles, 1/(x^2 + 1) = (-i/2)/(x - i) + (i/2)/(x + i)
+        let f = RationalFunction::new(poly(&[1.0]), poly(&[1.0, 0.0, 1.0]));
+        let i = Complex::new(0.0, 1.0);
+        let pf = f.partial_fractions(&[(i, 1), (-i, 1)]).expect("partial fractions failed");
+        assert_float_absolute_eq!((pf.terms[0].2 - (-i * 0.5)).norm(), 0.0, 1.0e-15);
+        assert_float_absolute_eq!((pf.terms[1].2 - (i * 0.5)).norm(), 0.0, 1.0e-15);
+
+        // roots from a root finder
+        let den = poly(&[6.0, 11.0, 6.0, 1.0]); // (x + 1)(x + 2)(x + 3)
+        let f = RationalFunction::new(poly(&[1.0, 2.0, 3.0]), den.clone());
+        let roots = crate::polynomial::roots::rpoly::rpoly(den.coeffs()).expect("rpoly failed");
+        let roots: Vec<_> = roots.into_iter().map(|r| (r, 1)).collect();
+        let pf = f.partial_fractions(&roots).expect("partial fractions failed");
+        for x in [-0.5, 0.0, 2.0] {
+            assert_float_relative_eq!(pf.eval(re(x)).re, f.eval(x), 1.0e-12);
+        }
+
+        assert_eq!(f.partial_fractions(&[(re(-1.0), 1)]).unwrap_err(), RationalErr::RootsMismatch);
+        assert_eq!(f.partial_fractions(&[(re(-1.0), 1), (re(-1.0), 2)]).unwrap_err(),
+            RationalErr::RepeatedRoot);
+    }
+
+    #[test]
+    fn pade() {
+        // exp(x)
+        let mut c = vec![1.0];
+        for k in 1..12 {
+            c.push(c[k - 1] / k as f64);
+        }
+        let f = RationalFunction::pade(&c, 2, 2).expect("Pade failed");
+        for (a, b) in f.numerator().coeffs().iter().zip([1.0, 0.5, 1.0/12.0]) {
+            assert_float_absolute_eq!(*a, b, 1.0e-15);
+        }
+        for (a, b) in f.denominator().coeffs().iter().zip([1.0, -0.5, 1.0/12.0]) {
+            assert_float_absolute_eq!(*a, b, 1.0e-15);
+        }
+
+        let f = RationalFunction::pade(&c, 5, 5).expect("Pade failed");
+        for x in [-1.0, -0.3, 0.4, 1.0] {
+            assert_float_relative_eq!(f.eval(x), x.exp(), 1.0e-9);
+        }
+
+        // [L/0] is the Taylor polynomial
+        let f = RationalFunction::pade(&c, 3, 0).expect("Pade failed");
+        assert_eq!(f.numerator().coeffs(), &c[..4]);
+        assert_eq!(f.denominator().coeffs(), &[1.0]);
+
+        // ln(1 + x) converges past the Taylor radius
+        let c: Vec<f64> = (0..9).map(|k| if k == 0 { 0.0 } else { (-1.0f64).powi(k + 1) / k as f64 })
+            .collect();
+        let f = RationalFunction::pade(&c, 4, 4).expect("Pade failed");
+        assert_float_absolute_eq!(f.eval(2.0), 3.0f64.ln(), 1.0e-4);
+
+        // cos(x) has no [1/1] approximant
+        assert_eq!(RationalFunction::pade(&[1.0, 0.0, -0.5], 1, 1).unwrap_err(), RationalErr::Singular);
+        assert_eq!(RationalFunction::pade(&[1.0, 1.0], 1, 1).unwrap_err(), RationalErr::NotEnoughTerms);
+    }
+}
@@ -1,9 +1,271 @@
 //! Functions to find roots of polynomial function
 //!
-//! https://en.wikipedia.org/wiki/Root-finding_algorithms
-//! https://en.wikipedia.org/wiki/Polynomial_root-finding_algorithms
+//! (c) Igor Lesik 2023
+//! MIT license
+//!
+//! Real roots are isolated with Sturm sequences and refined with [`crate::roots`] finders,
+//! all complex roots are found with [`simultaneous`] iterations
+//! or with Jenkins-Traub [`rpoly`] for real coefficients.
+//!
+//! References:
+//!
+//! - <https://en.wikipedia.org/wiki/Root-finding_algorithms>
+//! - <https://en.wikipedia.org/wiki/Polynomial_root-finding_algorithms>
+//! - <https://en.wikipedia.org/wiki/Sturm%27s_theorem>
+//! - <https://en.wikipedia.org/wiki/Geometrical_properties_of_polynomial_roots#Lagrange's_and_Cauchy's_bounds>
+
+use super::Polynomial;
+use crate::roots::{Convergence, RootsErr, itp::new_itp_finder};
+
+pub mod simultaneous;
+pub mod rpoly;
+
+/// Relative size of remainder coefficients that are treated as zeros
+const STURM_TOLERANCE: f64 = 1.0e-12;
+
+/// GCD tolerance for square-free factorization, see [`Polynomial::square_free`]
+const GCD_TOLERANCE: f64 = 1.0e-9;
+
+/// Sturm sequence `p0 = P, p1 = P', p(k+1) = -rem(p(k-1), p(k))`
+///
+/// Number of distinct real roots in `(a, b]` is `V(a) - V(b)`,
+/// where `V(x)` is number of sign changes in the sequence evaluated at `x`.
+///
+/// # Example
+///
+/// ```
+/// # use rustamath::polynomial::{Polynomial, roots::SturmSequence};
+/// // x^3 - x = x(x - 1)(x + 1)
+/// let sturm = SturmSequence::new(&Polynomial::new(vec![0.0, -1.0, 0.0, 1.0]));
+/// assert_eq!(sturm.count_roots(-2.0, 2.0), 3);
+/// assert_eq!(sturm.count_roots(-0.5, 2.0), 2);
+/// assert_eq!(sturm.count_roots(0.5, 0.9), 0);
+/// ```
+#[derive(Clone, Debug)]
+pub struct SturmSequence {
+    polys: Vec<Polynomial>,
+}
+
+impl SturmSequence {
+    /// Build Sturm sequence of the polynomial
+    pub fn new(p: &Polynomial) -> SturmSequence {
+        let mut polys = vec![p.clone()];
+
+        if p.degree() > 0 {
+            polys.push(p.derivative());
+        }
+
+        while polys[polys.len() - 1].degree() > 0 {
+            let n = polys.len();
+            let r = &polys[n - 2] % &polys[n - 1];
+            let scale = polys[n - 2].coeffs().iter().fold(0.0f64, |m, c| m.max(c.abs()));
+            let r = Polynomial::new(r.coeffs().iter()
+                .map(|c| if c.abs() <= STURM_TOLERANCE * scale { 0.0 } else { -c })
+                .collect());
+            if r.is_zero() {
+                break;
+            }
+            polys.push(r);
+        }
+
+        SturmSequence {polys}
+    }
+
+    /// Polynomials of the sequence
+    pub fn polynomials(&self) -> &[Polynomial] {
+        &self.polys
+    }
+
+    /// Number of sign changes `V(x)`, zeros are skipped
+    pub fn sign_changes(&self, x: f64) -> usize {
+        let mut changes = 0;
+        let mut last = 0.0;
+        for p in self.polys.iter() {
+            let y = p.eval(x);
+            if y == 0.0 {
+                continue;
+            }
+            if last * y < 0.0 {
+                changes += 1;
+            }
+            last = y;
+        }
+        changes
+    }
+
+    /// Number of distinct real roots in `(a, b]`
+    pub fn count_roots(&self, a: f64, b: f64) -> usize {
+        self.sign_changes(a).saturating_sub(self.sign_changes(b))
+    }
+}
+
+/// Cauchy bound, all roots satisfy `|x| < 1 + max|c_i/c_n|`
+pub fn root_bound(coeffs: &[f64]) -> f64 {
+    let p = Polynomial::new(coeffs.to_vec());
+    let lead = p.leading_coeff();
+    let c = p.coeffs();
+    1.0 + c[..c.len() - 1].iter().fold(0.0f64, |m, c| m.max((c / lead).abs()))
+}
+
+/// Real root of a polynomial
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RealRoot {
+    /// Root value
+    pub root: f64,
+    /// Multiplicity of the root
+    pub multiplicity: usize,
+}
+
+/// Find all distinct real roots of polynomial `c0 + c1*x + c2*x^2 + ...`
+///
+/// Polynomial is split into square-free factors, for each factor roots are isolated
+/// in `(-B, B]`, where `B` is [`root_bound`], by bisecting the interval
+/// till [`SturmSequence`] counts one root and the polynomial changes sign at the ends.
+/// Each isolated root is refined with ITP till the bracket is narrower than `epsilon`
+/// or than the float spacing at the root.
+/// Roots closer than `epsilon` are reported as one root. Returns roots in ascending order,
+/// or error of the ITP finder.
+///
+/// # Example
+///
+/// ```
+/// # use rustamath::polynomial::roots::find_real_roots;
+/// # use assert_float_eq::*;
+/// // (x - 1)^2 * (x + 2) * (x^2 + 1) = x^5 - 2x^3 + 2x^2 - 3x + 2
+/// let c = [2.0, -3.0, 2.0, -2.0, 0.0, 1.0];
+/// let roots = find_real_roots(&c, 1.0e-12).expect("find_real_roots failed");
+/// assert_eq!(roots.len(), 2);
+/// assert_float_absolute_eq!(roots[0].root, -2.0, 1.0e-12);
+/// assert_eq!(roots[0].multiplicity, 1);
+/// assert_float_absolute_eq!(roots[1].root, 1.0, 1.0e-12);
+/// assert_eq!(roots[1].multiplicity, 2);
+/// ```
+pub fn find_real_roots(coeffs: &[f64], epsilon: f64) -> Result<Vec<RealRoot>, RootsErr<()>> {
+    let p = Polynomial::new(coeffs.to_vec());
+
+    let mut roots = Vec::new();
+
+    for (factor, multiplicity) in p.square_free(GCD_TOLERANCE) {
+        for root in isolate_and_refine(&factor, epsilon)? {
+            roots.push(RealRoot {root, multiplicity});
+        }
+    }
+
+    roots.sort_by(|a, b| a.root.total_cmp(&b.root));
+    Ok(roots)
+}
+
+/// Roots of square-free polynomial
+fn isolate_and_refine(p: &Polynomial, epsilon: f64) -> Result<Vec<f64>, RootsErr<()>> {
+    let sturm = SturmSequence::new(p);
+    let bound = root_bound(p.coeffs());
+
+    let finder = new_itp_finder();
+    // relative term stops at the float spacing when `epsilon` is below it
+    let criteria = [Convergence::Interval{epsabs: epsilon, epsrel: f64::EPSILON}];
+
+    let mut roots = Vec::new();
+    let mut intervals = vec![(-bound, bound)];
+
+    while let Some((a, b)) = intervals.pop() {
+        let count = sturm.count_roots(a, b);
+        if count == 0 {
+            continue;
+        }
+
+        let (fa, fb) = (p.eval(a), p.eval(b));
+        let m = 0.5 * (a + b);
+
+        if count == 1 && fb == 0.0 {
+            // Sturm counts roots in (a, b], the root is b itself
+            roots.push(b);
+        } else if b - a <= epsilon || !(a < m && m < b) {
+            // roots closer than epsilon
+            roots.push(m);
+        } else if count == 1 && fa * fb < 0.0 {
+            let res = finder.find_with(|x| Ok::<f64, ()>(p.eval(x)), a, b, &criteria, 200)?;
+            let (lo, hi, _, _) = res.range;
+            let mid = 0.5 * (lo + hi);
+            // bracket that can not shrink any more is converged
+            if res.max_iterations_reached && lo < mid && mid < hi {
+                return Err(RootsErr::NoProgress);
+            }
+            roots.push(res.root);
+        } else {
+            // more roots, or root at a that does not belong to (a, b]
+            intervals.push((a, m));
+            intervals.push((m, b));
+        }
+    }
+
+    Ok(roots)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::polynomial::test_helpers::from_roots;
+
+    #[test]
+    fn real_roots() {
+        // Wilkinson like polynomial with distinct roots
+        let p = from_roots(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]);
+        let roots = find_real_roots(p.coeffs(), 1.0e-12).expect("find_real_roots failed");
+        assert_eq!(roots.len(), 8);
+        for (i, r) in roots.iter().enumerate() {
+            assert_float_absolute_eq!(r.root, (i + 1) as f64, 1.0e-9);
+            assert_eq!(r.multiplicity, 1);
+        }
+
+        // multiple roots and zero root
+        let p = from_roots(&[0.0, 0.0, -1.5, -1.5, -1.5, 2.0, 0.25]);
+        let roots = find_real_roots(p.coeffs(), 1.0e-12).expect("find_real_roots failed");
+        let expected = [(-1.5, 3), (0.0, 2), (0.25, 1), (2.0, 1)];
+        assert_eq!(roots.len(), expected.len());
+        for (r, (root, m)) in roots.iter().zip(expected) {
+            assert_float_absolute_eq!(r.root, root, 1.0e-8);
+            assert_eq!(r.multiplicity, m);
+        }
+
+        // close roots
+        let p = from_roots(&[1.0, 1.001, -3.0]) * 0.5;
+        let roots = find_real_roots(p.coeffs(), 1.0e-12).expect("find_real_roots failed");
+        assert_eq!(roots.len(), 3);
+        assert_float_absolute_eq!(roots[1].root, 1.0, 1.0e-10);
+        assert_float_absolute_eq!(roots[2].root, 1.001, 1.0e-10);
+
+        // no real roots
+        assert!(find_real_roots(&[1.0, 0.0, 1.0], 1.0e-12).expect("find_real_roots failed").is_empty());
+        assert!(find_real_roots(&[3.0], 1.0e-12).expect("find_real_roots failed").is_empty());
+
+        // roots at bisection points of (-B, B], f(a) = 0 or f(b) = 0 for some intervals
+        for (coeffs, expected) in [
+            (vec![0.0, -1.0, 1.0], vec![0.0, 1.0]),
+            (vec![0.0, 2.0, -3.0, 1.0], vec![0.0, 1.0, 2.0]),
+            (from_roots(&[-2.0, -1.0, -0.5, 0.0, 0.5, 1.0, 2.0]).into_coeffs(),
+                vec![-2.0, -1.0, -0.5, 0.0, 0.5, 1.0, 2.0]),
+            (from_roots(&[0.0, 0.0, 4.0, 4.0, 6.0]).into_coeffs(), vec![0.0, 4.0, 6.0]),
+        ] {
+            let roots = find_real_roots(&coeffs, 1.0e-12).expect("find_real_roots failed");
+            assert_eq!(roots.len(), expected.len(), "{:?}", roots);
+            for (r, root) in roots.iter().zip(expected) {
+                assert_float_absolute_eq!(r.root, root, 1.0e-9);
+            }
+        }
+
+        // epsilon below the float spacing at the root
+        for (coeffs, epsilon) in [([-2.0e12, 0.0, 1.0], 1.0e-12), ([-2.0, 0.0, 1.0], 0.0)] {
+            let roots = find_real_roots(&coeffs, epsilon).expect("find_real_roots failed");
+            let root = (-coeffs[0]).sqrt();
+            assert_eq!(roots.len(), 2);
+            assert_float_relative_eq!(roots[0].root, -root, 4.0 * f64::EPSILON);
+            assert_float_relative_eq!(roots[1].root, root, 4.0 * f64::EPSILON);
+        }
 
-/// TODO
-pub fn find_real_roots(n: i32) -> i32 {
-    n
+        let sturm = SturmSequence::new(&from_roots(&[-1.0, 0.5, 3.0]));
+        assert_eq!(sturm.count_roots(-10.0, 10.0), 3);
+        assert_eq!(sturm.count_roots(0.5, 3.0), 1);
+        assert_eq!(sturm.count_roots(-1.0, 0.5), 1);
+        assert_float_absolute_eq!(root_bound(&[-6.0, 1.0, 1.0]), 7.0, 1.0e-15);
+    }
 }
@@ -0,0 +1,842 @@
+//! Jenkins-Traub RPOLY root finding algorithm for real polynomials.
+//!
+//! (c) Igor Lesik 2023
+//! MIT license
+//!
+//! Port of the classic three-stage algorithm for polynomials with real coefficients:
+//! no-shift stage, fixed-shift stage and variable-shift stage.
+//! Each stage computes the sequence of `K` polynomials that converge
+//! to `P(x)/(x - s)` for a real zero `s` or to `P(x)/(x^2 + u*x + v)` for a pair of
+//! complex conjugate zeros. Found zeros are removed by deflation.
+//!
+//! References:
+//!
+//! - <https://en.wikipedia.org/wiki/Jenkins%E2%80%93Traub_algorithm>
+//! - M.A. Jenkins, Algorithm 493: Zeros of a Real Polynomial, ACM TOMS 1(2), 1975
+//! - <https://www.netlib.org/toms/493>
+
+use num_complex::Complex;
+use crate::roots::RootsErr;
+
+/// Number of fixed shifts tried before giving up
+const MAX_SHIFTS: usize = 20;
+
+/// Iteration limit of the loops estimating the lower bound of zeros
+const MAX_BOUND_ITERATIONS: usize = 1000;
+
+/// Working state of RPOLY, `p` coefficients are stored from the highest degree
+struct Rpoly {
+    p: Vec<f64>,
+    qp: Vec<f64>,
+    k: Vec<f64>,
+    qk: Vec<f64>,
+    svk: Vec<f64>,
+    // degree and number of coefficients of the current polynomial
+    n: usize,
+    nn: usize,
+    // quadratic factor x^2 + u*x + v and remainder b*(x + u) + a of P
+    u: f64,
+    v: f64,
+    a: f64,
+    b: f64,
+    // remainder d*(x + u) + c of K and intermediate values of calcsc
+    c: f64,
+    d: f64,
+    e: f64,
+    f: f64,
+    g: f64,
+    h: f64,
+    a1: f64,
+    a3: f64,
+    a7: f64,
+    // found zeros, small and large
+    szr: f64,
+    szi: f64,
+    lzr: f64,
+    lzi: f64,
+    // machine precision, error bounds of addition and multiplication
+    eta: f64,
+    are: f64,
+    mre: f64,
+}
+
+/// How the next K polynomial is calculated, see `calcsc`
+#[derive(Clone, Copy, PartialEq)]
+enum Kind {
+    DividedByC,
+    DividedByD,
+    /// K is almost exactly divisible by the quadratic
+    NearlyDivisible,
+}
+
+/// Divide `p` by `x^2 + u*x + v`, quotient is `q[..nn-2]`, remainder is `b*(x + u) + a`
+fn quadsd(nn: usize, u: f64, v: f64, p: &[f64], q: &mut [f64]) -> (f64, f64) {
+    let mut b = p[0];
+    q[0] = b;
+    let mut a = p[1] - u * b;
+    q[1] = a;
+    for i in 2..nn {
+        let c = p[i] - u * a - v * b;
+        q[i] = c;
+        b = a;
+        a = c;
+    }
+    (a, b)
+}
+
+/// Zeros of `a*x^2 + b1*x + c` as `(sr, si, lr, li)`, small and large
+fn quad(a: f64, b1: f64, c: f64) -> (f64, f64, f64, f64) {
+    if a == 0.0 {
+        let sr = if b1 != 0.0 { -c / b1 } else { 0.0 };
+        return (sr, 0.0, 0.0, 0.0);
+    }
+    if c == 0.0 {
+        return (0.0, 0.0, -b1 / a, 0.0);
+    }
+
+    // compute discriminant avoiding overflow
+    let b = b1 / 2.0;
+    let (e, d) = if b.abs() < c.abs() {
+        let e = if c < 0.0 { -a } else { a };
+        let e = b * (b / c.abs()) - e;
+        (e, e.abs().sqrt() * c.abs().sqrt())
+    } else {
+        let e = 1.0 - (a / b) * (c / b);
+        (e, e.abs().sqrt() * b.abs())
+    };
+
+    if e < 0.0 {
+        // complex conjugate zeros
+        let sr = -b / a;
+        let si = (d / a).abs();
+        (sr, si, sr, -si)
+    } else {
+        // real zeros
+        let d = if b >= 0.0 { -d } else { d };
+        let lr = (-b + d) / a;
+        let sr = if lr != 0.0 { (c / lr) / a } else { 0.0 };
+        (sr, 0.0, lr, 0.0)
+    }
+}
+
+impl Rpoly {
+    fn new(p: Vec<f64>) -> Rpoly {
+        let nn = p.len();
+        let eta = f64::EPSILON;
+        Rpoly {
+            qp: vec![0.0; nn],
+            k: vec![0.0; nn],
+            qk: vec![0.0; nn],
+            svk: vec![0.0; nn],
+            p,
+            n: nn - 1,
+            nn,
+            u: 0.0, v: 0.0, a: 0.0, b: 0.0,
+            c: 0.0, d: 0.0, e: 0.0, f: 0.0, g: 0.0, h: 0.0,
+            a1: 0.0, a3: 0.0, a7: 0.0,
+            szr: 0.0, szi: 0.0, lzr: 0.0, lzi: 0.0,
+            eta,
+            are: eta,
+            mre: eta,
+        }
+    }
+
+    /// Scale coefficients by a power of 2 to avoid overflow and undetected underflow
+    fn scale(&mut self) {
+        let lo = f64::MIN_POSITIVE / self.eta;
+        let p = &mut self.p[..self.nn];
+
+        let max = p.iter().fold(0.0f64, |m, c| m.max(c.abs()));
+        let min = p.iter().filter(|c| **c != 0.0).fold(f64::INFINITY, |m, c| m.min(c.abs()));
+
+        let mut sc = lo / min;
+        if sc > 1.0 && f64::MAX / sc < max {
+            return;
+        }
+        if sc <= 1.0 {
+            if max < 10.0 {
+                return;
+            }
+            if sc == 0.0 {
+                sc = f64::MIN_POSITIVE;
+            }
+        }
+        let l = (sc.log2() + 0.5).floor() as i32;
+        let factor = 2.0f64.powi(l);
+        if factor != 1.0 && factor.is_finite() && factor > 0.0 {
+            for c in p.iter_mut() {
+                *c *= factor;
+            }
+        }
+    }
+
+    /// Lower bound on moduli of zeros, positive root of Cauchy polynomial
+    fn lower_bound(&self) -> f64 {
+        let n = self.n;
+        let mut pt: Vec<f64> = self.p[..self.nn].iter().map(|c| c.abs()).collect();
+        pt[n] = -pt[n];
+
+        // upper estimate of the bound
+        let mut x = ((-pt[n]).ln() - pt[0].ln()) / n as f64;
+        x = x.exp();
+        // Newton step at the origin is better
+        if pt[n - 1] != 0.0 {
+            let xm = -pt[n] / pt[n - 1];
+            if xm < x {
+                x = xm;
+            }
+        }
+
+        // chop the interval (0, x) until ff <= 0
+        for _ in 0..MAX_BOUND_ITERATIONS {
+            let xm = x * 0.1;
+            let ff = pt.iter().skip(1).fold(pt[0], |ff, c| ff * xm + c);
+            if ff <= 0.0 {
+                break;
+            }
+            x = xm;
+        }
+
+        // Newton iterations until x converges to two decimal places
+        let mut dx = x;
+        for _ in 0..MAX_BOUND_ITERATIONS {
+            if (dx / x).abs() <= 0.005 {
+                break;
+            }
+            let mut ff = pt[0];
+            let mut df = ff;
+            for c in pt.iter().take(n).skip(1) {
+                ff = ff * x + c;
+                df = df * x + ff;
+            }
+            ff = ff * x + pt[n];
+            dx = ff / df;
+            x -= dx;
+        }
+        x
+    }
+
+    /// No-shift stage, start from the derivative and do 5 steps
+    fn no_shift(&mut self) {
+        let n = self.n;
+        let nn = self.nn;
+
+        for i in 1..n {
+            self.k[i] = (n - i) as f64 * self.p[i] / n as f64;
+        }
+        self.k[0] = self.p[0];
+
+        let aa = self.p[nn - 1];
+        let bb = self.p[nn - 2];
+        let mut zerok = self.k[n - 1] == 0.0;
+
+        for _ in 0..5 {
+            let cc = self.k[n - 1];
+            if !zerok {
+                // scaled form of recurrence if value of K at 0 is nonzero
+                let t = -aa / cc;
+                for j in (2..=n).rev() {
+                    self.k[j - 1] = t * self.k[j - 2] + self.p[j - 1];
+                }
+                self.k[0] = self.p[0];
+                zerok = self.k[n - 1].abs() <= bb.abs() * self.eta * 10.0;
+            } else {
+                // unscaled form of recurrence
+                for j in (2..=n).rev() {
+                    self.k[j - 1] = self.k[j - 2];
+                }
+                self.k[0] = 0.0;
+                zerok = self.k[n - 1] == 0.0;
+            }
+        }
+    }
+
+    /// Calculate scalar quantities used to compute the next K polynomial and new estimates
+    fn calcsc(&mut self) -> Kind {
+        let n = self.n;
+        // synthetic division of K by the quadratic
+        let (c, d) = quadsd(n, self.u, self.v, &self.k, &mut self.qk);
+        self.c = c;
+        self.d = d;
+
+        if c.abs() <= self.k[n - 1].abs() * 100.0 * self.eta &&
+            d.abs() <= self.k[n - 2].abs() * 100.0 * self.eta
+        {
+            return Kind::NearlyDivisible;
+        }
+
+        let (a, b, u, v) = (self.a, self.b, self.u, self.v);
+
+        if d.abs() < c.abs() {
+            self.e = a / c;
+            self.f = d / c;
+            self.g = u * self.e;
+            self.h = v * b;
+            self.a3 = a * self.e + (self.h / c + self.g) * b;
+            self.a1 = b - a * (d / c);
+            self.a7 = a + self.g * d + self.h * self.f;
+            Kind::DividedByC
+        } else {
+            self.e = a / d;
+            self.f = c / d;
+            self.g = u * b;
+            self.h = v * b;
+            self.a3 = (a + self.g) * self.e + self.h * (b / d);
+            self.a1 = b * self.f - a;
+            self.a7 = (self.f + u) * a + self.h;
+            Kind::DividedByD
+        }
+    }
+
+    /// Compute the next K polynomial
+    fn nextk(&mut self, kind: Kind) {
+        let n = self.n;
+
+        if kind == Kind::NearlyDivisible {
+            // use unscaled form of the recurrence
+            self.k[0] = 0.0;
+            self.k[1] = 0.0;
+            for i in 2..n {
+                self.k[i] = self.qk[i - 2];
+            }
+            return;
+        }
+
+        let temp = if kind == Kind::DividedByC { self.b } else { self.a };
+
+        if self.a1.abs() <= temp.abs() * self.eta * 10.0 {
+            // if a1 is nearly zero then use a special form of the recurrence
+            self.k[0] = 0.0;
+            self.k[1] = -self.a7 * self.qp[0];
+            for i in 2..n {
+                self.k[i] = self.a3 * self.qk[i - 2] - self.a7 * self.qp[i - 1];
+            }
+            return;
+        }
+
+        // use scaled form of the recurrence
+        self.a7 /= self.a1;
+        self.a3 /= self.a1;
+        self.k[0] = self.qp[0];
+        self.k[1] = self.qp[1] - self.a7 * self.qp[0];
+        for i in 2..n {
+            self.k[i] = self.a3 * self.qk[i - 2] - self.a7 * self.qp[i - 1] + self.qp[i];
+        }
+    }
+
+    /// Compute new estimates of the quadratic coefficients `(u, v)`
+    fn newest(&self, kind: Kind) -> (f64, f64) {
+        if kind == Kind::NearlyDivisible {
+            return (0.0, 0.0);
+        }
+
+        let (a, b, c, d) = (self.a, self.b, self.c, self.d);
+        let (f, g, h, u, v) = (self.f, self.g, self.h, self.u, self.v);
+        let n = self.n;
+
+        let (a4, a5) = if kind == Kind::DividedByD {
+            ((a + g) * f + h, (f + u) * c + v * d)
+        } else {
+            (a + u * b + h * f, c + (u + v * f) * d)
+        };
+
+        // evaluate new quadratic coefficients
+        let b1 = -self.k[n - 1] / self.p[n];
+        let b2 = -(self.k[n - 2] + b1 * self.p[n - 1]) / self.p[n];
+        let c1 = v * b2 * self.a1;
+        let c2 = b1 * self.a7;
+        let c3 = b1 * b1 * self.a3;
+        let c4 = c1 - c2 - c3;
+        let temp = a5 + b1 * a4 - c4;
+
+        if temp == 0.0 {
+            return (0.0, 0.0);
+        }
+
+        let uu = u - (u * (c3 + c2) + v * (b1 * self.a1 + b2 * self.a7)) / temp;
+        let vv = v * (1.0 + c4 / temp);
+        (uu, vv)
+    }
+
+    /// Variable-shift iteration for a quadratic factor, returns number of found zeros
+    fn quadit(&mut self, uu: f64, vv: f64) -> usize {
+        let n = self.n;
+        let nn = self.nn;
+
+        let mut tried = false;
+        let mut omp = 0.0;
+        let mut relstp: f64 = 0.0;
+        self.u = uu;
+        self.v = vv;
+        let mut j = 0;
+
+        loop {
+            let (szr, szi, lzr, lzi) = quad(1.0, self.u, self.v);
+            (self.szr, self.szi, self.lzr, self.lzi) = (szr, szi, lzr, lzi);
+
+            // return if roots of the quadratic are real and not close to multiple
+            // or nearly equal and of opposite sign
+            if (szr.abs() - lzr.abs()).abs() > 0.01 * lzr.abs() {
+                return 0;
+            }
+
+            // evaluate polynomial by quadratic synthetic division
+            (self.a, self.b) = quadsd(nn, self.u, self.v, &self.p, &mut self.qp);
+            let (a, b) = (self.a, self.b);
+
+            let mp = (a - szr * b).abs() + (szi * b).abs();
+
+            // compute a rigorous bound on the rounding error in evaluating P
+            let zm = self.v.abs().sqrt();
+            let t = -szr * b;
+            let mut ee = 2.0 * self.qp[0].abs();
+            for i in 1..n {
+                ee = ee * zm + self.qp[i].abs();
+            }
+            ee = ee * zm + (a + t).abs();
+            ee = ee * (5.0 * self.mre + 4.0 * self.are)
+                - (5.0 * self.mre + 2.0 * self.are) * ((a + t).abs() + b.abs() * zm)
+                + 2.0 * self.are * t.abs();
+
+            // iteration has converged if polynomial value is less than 20 times the bound
+            if mp <= 20.0 * ee {
+                return 2;
+            }
+
+            j += 1;
+            if j > 20 {
+                return 0;
+            }
+
+            if j >= 2 && !(relstp > 0.01 || mp < omp || tried) {
+                // a cluster appears to be stalling the convergence,
+                // five fixed shift steps are taken with u, v close to the cluster
+                let relstp_sqrt = relstp.max(self.eta).sqrt();
+                self.u -= self.u * relstp_sqrt;
+                self.v += self.v * relstp_sqrt;
+                (self.a, self.b) = quadsd(nn, self.u, self.v, &self.p, &mut self.qp);
+                for _ in 0..5 {
+                    let kind = self.calcsc();
+                    self.nextk(kind);
+                }
+                tried = true;
+                j = 0;
+            }
+
+            omp = mp;
+
+            // calculate next K polynomial and new u and v
+            let kind = self.calcsc();
+            self.nextk(kind);
+            let kind = self.calcsc();
+            let (ui, vi) = self.newest(kind);
+
+            // if vi is zero the iteration is not converging
+            if vi == 0.0 {
+                return 0;
+            }
+
+            relstp = ((vi - self.v) / vi).abs();
+            self.u = ui;
+            self.v = vi;
+        }
+    }
+
+    /// Variable-shift iteration for a real zero, returns number of found zeros and
+    /// `Some(s)` if a cluster of zeros near the real axis needs quadratic iteration
+    fn realit(&mut self, sss: f64) -> (usize, Option<f64>) {
+        let n = self.n;
+        let nn = self.nn;
+
+        let mut s = sss;
+        let mut t: f64 = 0.0;
+        let mut omp = 0.0;
+        let mut j = 0;
+
+        loop {
+            // evaluate P at s
+            let mut pv = self.p[0];
+            self.qp[0] = pv;
+            for i in 1..nn {
+                pv = pv * s + self.p[i];
+                self.qp[i] = pv;
+            }
+            let mp = pv.abs();
+
+            // compute a rigorous bound on the error in evaluating P
+            let ms = s.abs();
+            let mut ee = (self.mre / (self.are + self.mre)) * self.qp[0].abs();
+            for i in 1..nn {
+                ee = ee * ms + self.qp[i].abs();
+            }
+
+            // iteration has converged if polynomial value is less than 20 times the bound
+            if mp <= 20.0 * ((self.are + self.mre) * ee - self.mre * mp) {
+                self.szr = s;
+                self.szi = 0.0;
+                return (1, None);
+            }
+
+            j += 1;
+            if j > 10 {
+                return (0, None);
+            }
+
+            if j >= 2 && !(t.abs() > 0.001 * (s - t).abs() || mp < omp) {
+                // a cluster of zeros near the real axis has been encountered,
+                // return to initiate a quadratic iteration
+                return (0, Some(s));
+            }
+
+            omp = mp;
+
+            // compute t, the next polynomial, and the new iterate
+            let mut kv = self.k[0];
+            self.qk[0] = kv;
+            for i in 1..n {
+                kv = kv * s + self.k[i];
+                self.qk[i] = kv;
+            }
+
+            if kv.abs() <= self.k[n - 1].abs() * 10.0 * self.eta {
+                // use unscaled form
+                self.k[0] = 0.0;
+                for i in 1..n {
+                    self.k[i] = self.qk[i - 1];
+                }
+            } else {
+                // use the scaled form of the recurrence if the value of K at s is nonzero
+                let tt = -pv / kv;
+                self.k[0] = self.qp[0];
+                for i in 1..n {
+                    self.k[i] = tt * self.qk[i - 1] + self.qp[i];
+                }
+            }
+
+            let kv = self.k.iter().take(n).skip(1).fold(self.k[0], |kv, k| kv * s + k);
+            t = if kv.abs() > self.k[n - 1].abs() * 10.0 * self.eta { -pv / kv } else { 0.0 };
+            s += t;
+        }
+    }
+
+    /// Fixed-shift stage with `l2` steps, then variable-shift stage,
+    /// returns number of found zeros
+    fn fxshfr(&mut self, l2: usize, sr: f64) -> usize {
+        let n = self.n;
+
+        let mut betav = 0.25;
+        let mut betas = 0.25;
+        let mut oss = sr;
+        let mut ovv = self.v;
+        let mut otv = 0.0;
+        let mut ots = 0.0;
+
+        // evaluate polynomial by synthetic division
+        (self.a, self.b) = quadsd(self.nn, self.u, self.v, &self.p, &mut self.qp);
+        let mut kind = self.calcsc();
+
+        for j in 0..l2 {
+            // calculate next K polynomial and estimate v
+            self.nextk(kind);
+            kind = self.calcsc();
+            let (ui, vi) = self.newest(kind);
+            let vv = vi;
+
+            // estimate s
+            let ss = if self.k[n - 1] != 0.0 { -self.p[n] / self.k[n - 1] } else { 0.0 };
+
+            let mut tv = 1.0;
+            let mut ts = 1.0;
+
+            if j != 0 && kind != Kind::NearlyDivisible {
+                // compute relative measures of convergence of s and v sequences
+                if vv != 0.0 {
+                    tv = ((vv - ovv) / vv).abs();
+                }
+                if ss != 0.0 {
+                    ts = ((ss - oss) / ss).abs();
+                }
+
+                // if decreasing, multiply two most recent convergence measures
+                let tvv = if tv < otv { tv * otv } else { 1.0 };
+                let tss = if ts < ots { ts * ots } else { 1.0 };
+
+                // compare with convergence criteria
+                let vpass = tvv < betav;
+                let spass = tss < betas;
+
+                if spass || vpass {
+                    let nz = self.variable_shift(ui, vi, ss, spass, vpass, tss, tvv,
+                        &mut betas, &mut betav);
+                    if nz > 0 {
+                        return nz;
+                    }
+                    // iterations failed, restart fixed shift from saved state
+                    (self.a, self.b) = quadsd(self.nn, self.u, self.v, &self.p, &mut self.qp);
+                    kind = self.calcsc();
+                }
+            }
+
+            ovv = vv;
+            oss = ss;
+            otv = tv;
+            ots = ts;
+        }
+
+        0
+    }
+
+    /// Try quadratic and linear iterations once sequences of `v` or `s` start to converge
+    #[allow(clippy::too_many_arguments)]
+    fn variable_shift(
+        &mut self,
+        ui: f64,
+        vi: f64,
+        ss: f64,
+        spass: bool,
+        vpass: bool,
+        tss: f64,
+        tvv: f64,
+        betas: &mut f64,
+        betav: &mut f64,
+    ) -> usize {
+        let n = self.n;
+
+        // save the current quadratic and K polynomial
+        let (svu, svv) = (self.u, self.v);
+        self.svk[..n].copy_from_slice(&self.k[..n]);
+
+        let mut s = ss;
+        let (mut ui, mut vi) = (ui, vi);
+        let mut stry = false;
+        let mut vtry = false;
+
+        // choose iteration according to the fastest converging sequence
+        let mut try_real = spass && (!vpass || tss < tvv);
+
+        loop {
+            if !try_real {
+                let nz = self.quadit(ui, vi);
+                if nz > 0 {
+                    return nz;
+                }
+                // quadratic iteration has failed, flag that it has been tried
+                // and decrease the convergence criterion
+                vtry = true;
+                *betav *= 0.25;
+
+                // try linear iteration if it has not been tried and the s sequence is converging
+                if stry || !spass {
+                    // restore variables and retry quadratic if v sequence converges
+                    self.u = svu;
+                    self.v = svv;
+                    self.k[..n].copy_from_slice(&self.svk[..n]);
+                    if vpass && !vtry {
+                        continue;
+                    }
+                    return 0;
+                }
+                self.k[..n].copy_from_slice(&self.svk[..n]);
+            }
+
+            let (nz, cluster) = self.realit(s);
+            if nz > 0 {
+                return nz;
+            }
+
+            // linear iteration has failed, flag that it has been tried
+            // and decrease the convergence criterion
+            stry = true;
+            *betas *= 0.25;
+
+            if let Some(sc) = cluster {
+                // zeros are clustered near the real axis, try quadratic iteration
+                s = sc;
+                ui = -(s + s);
+                vi = s * s;
+                try_real = false;
+                continue;
+            }
+
+            // restore variables
+            self.u = svu;
+            self.v = svv;
+            self.k[..n].copy_from_slice(&self.svk[..n]);
+            if vpass && !vtry {
+                try_real = false;
+                continue;
+            }
+            return 0;
+        }
+    }
+}
+
+/// Find all zeros of real polynomial `c0 + c1*x + c2*x^2 + ...` with Jenkins-Traub RPOLY
+///
+/// Highest degree zero coefficients are dropped, zero constant terms give zero roots.
+/// Coefficients are scaled by a power of 2 to avoid overflow, clusters of zeros
+/// are handled by extra fixed-shift steps. Complex zeros come in conjugate pairs.
+///
+/// Returns `RootsErr::NotFinite` if a coefficient is NaN or infinite,
+/// `RootsErr::NoProgress` if no zero is found after 20 shifts.
+///
+/// # Example
+///
+/// ```
+/// # use rustamath::polynomial::roots::rpoly::rpoly;
+/// # use assert_float_eq::*;
+/// // (x - 1)(x - 2)(x^2 + 1)
+/// let mut roots = rpoly(&[2.0, -3.0, 3.0, -3.0, 1.0]).unwrap();
+/// roots.sort_by(|a, b| (a.re, a.im).partial_cmp(&(b.re, b.im)).unwrap());
+/// assert_float_absolute_eq!(roots[0].im, -1.0, 1.0e-14);
+/// assert_float_absolute_eq!(roots[1].im,  1.0, 1.0e-14);
+/// assert_float_absolute_eq!(roots[2].re,  1.0, 1.0e-14);
+/// assert_float_absolute_eq!(roots[3].re,  2.0, 1.0e-14);
+/// ```
+pub fn rpoly(coeffs: &[f64]) -> Result<Vec<Complex<f64>>, RootsErr<()>> {
+    if !coeffs.iter().all(|c| c.is_finite()) {
+        return Err(RootsErr::NotFinite);
+    }
+
+    // highest degree first, without leading zeros
+    let mut op: Vec<f64> = coeffs.iter().rev().copied().skip_while(|c| *c == 0.0).collect();
+
+    let mut roots = Vec::new();
+
+    // remove the zeros at the origin
+    while op.len() > 1 && op[op.len() - 1] == 0.0 {
+        op.pop();
+        roots.push(Complex::new(0.0, 0.0));
+    }
+
+    if op.len() < 2 {
+        return Ok(roots);
+    }
+
+    let mut rp = Rpoly::new(op);
+
+    // rotation of the shift by 94 degrees
+    let (sinr, cosr) = (94.0f64).to_radians().sin_cos();
+    let mut xx = std::f64::consts::FRAC_1_SQRT_2;
+    let mut yy = -xx;
+
+    while rp.n > 2 {
+        rp.scale();
+        let bnd = rp.lower_bound();
+
+        rp.no_shift();
+
+        // save K for restarts with new shifts
+        let temk = rp.k[..rp.n].to_vec();
+
+        let mut nz = 0;
+        for cnt in 0..MAX_SHIFTS {
+            // quadratic corresponds to a double shift to a non-real point and its
+            // complex conjugate, the point has modulus bnd and amplitude rotated
+            // by 94 degrees from the previous shift
+            let xxx = cosr * xx - sinr * yy;
+            yy = sinr * xx + cosr * yy;
+            xx = xxx;
+            let sr = bnd * xx;
+            rp.u = -2.0 * sr;
+            rp.v = bnd * bnd;
+
+            // second stage calculation, fixed quadratic
+            nz = rp.fxshfr(20 * (cnt + 1), sr);
+            if nz != 0 {
+                break;
+            }
+
+            // if the iteration is unsuccessful another quadratic is chosen after restoring K
+            let n = rp.n;
+            rp.k[..n].copy_from_slice(&temk);
+        }
+
+        if nz == 0 {
+            return Err(RootsErr::NoProgress);
+        }
+
+        // deflate the polynomial, store the zero or zeros
+        roots.push(Complex::new(rp.szr, rp.szi));
+        if nz == 2 {
+            roots.push(Complex::new(rp.lzr, rp.lzi));
+        }
+        rp.nn -= nz;
+        rp.n = rp.nn - 1;
+        let nn = rp.nn;
+        rp.p[..nn].copy_from_slice(&rp.qp[..nn]);
+    }
+
+    let p = &rp.p;
+    if rp.n == 1 {
+        roots.push(Complex::new(-p[1] / p[0], 0.0));
+    } else {
+        let (sr, si, lr, li) = quad(p[0], p[1], p[2]);
+        roots.push(Complex::new(sr, si));
+        roots.push(Complex::new(lr, li));
+    }
+
+    Ok(roots)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::polynomial::test_helpers::{from_roots, check_roots};
+
+    #[test]
+    fn rpoly_roots() {
+        let real = |roots: &[f64]| roots.iter().map(|r| Complex::new(*r, 0.0)).collect::<Vec<_>>();
+
+        // Wilkinson polynomial of degree 10
+        let expected: Vec<f64> = (1..=10).map(|k| k as f64).collect();
+        let roots = rpoly(&from_roots(&expected).into_coeffs()).expect("rpoly failed");
+        check_roots("wilkinson", &roots, &real(&expected), 1.0e-9);
+
+        // roots of unity, complex conjugate pairs
+        let n = 15;
+        let mut c = vec![0.0; n + 1];
+        c[0] = -1.0;
+        c[n] = 1.0;
+        let expected: Vec<_> = (0..n)
+            .map(|k| Complex::from_polar(1.0, 2.0 * std::f64::consts::PI * k as f64 / n as f64))
+            .collect();
+        check_roots("unity", &rpoly(&c).expect("rpoly failed"), &expected, 1.0e-13);
+
+        // zero roots and zero highest degree coefficients
+        let expected = [0.0, 0.0, 3.0, -1.5];
+        let mut c = from_roots(&expected).into_coeffs();
+        c.push(0.0);
+        c.push(0.0);
+        check_roots("zeros", &rpoly(&c).expect("rpoly failed"), &real(&expected), 1.0e-14);
+
+        // clustered roots
+        let expected = [1.0, 1.001, 1.002, 0.999, -5.0];
+        check_roots("cluster", &rpoly(&from_roots(&expected).into_coeffs()).expect("rpoly failed"),
+            &real(&expected), 1.0e-5);
+
+        // multiple root
+        let expected = [2.0, 2.0, 2.0, 2.0, -1.0];
+        check_roots("multiple", &rpoly(&from_roots(&expected).into_coeffs()).expect("rpoly failed"),
+            &real(&expected), 1.0e-3);
+
+        // huge and tiny coefficients need scaling
+        let expected = [1.0e-3, 2.0, 5.0e3, -7.0];
+        let c: Vec<f64> = from_roots(&expected).into_coeffs().iter().map(|c| c * 1.0e-300).collect();
+        check_roots("tiny", &rpoly(&c).expect("rpoly failed"), &real(&expected), 1.0e-9);
+        let c: Vec<f64> = from_roots(&expected).into_coeffs().iter().map(|c| c * 1.0e290).collect();
+        check_roots("huge", &rpoly(&c).expect("rpoly failed"), &real(&expected), 1.0e-9);
+
+        // low degrees
+        assert!(rpoly(&[4.0]).expect("rpoly failed").is_empty());
+        check_roots("linear", &rpoly(&[3.0, 2.0]).expect("rpoly failed"), &real(&[-1.5]), 1.0e-15);
+        check_roots("quadratic", &rpoly(&[1.0, 0.0, 1.0]).expect("rpoly failed"),
+            &[Complex::new(0.0, 1.0), Complex::new(0.0, -1.0)], 1.0e-15);
+
+        // bad coefficients
+        for bad in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+            assert!(matches!(rpoly(&[1.0, bad, 2.0, 1.0]), Err(RootsErr::NotFinite)));
+            assert!(matches!(rpoly(&[bad]), Err(RootsErr::NotFinite)));
+        }
+    }
+}
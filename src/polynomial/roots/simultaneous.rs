@@ -0,0 +1,272 @@
+//! All complex roots of a polynomial by simultaneous iterations.
+//!
+//! (c) Igor Lesik 2023
+//! MIT license
+//!
+//! All `n` root estimates are improved at once, no deflation is needed,
+//! so the roots are not spoiled by rounding errors of deflated coefficients.
+//!
+//! References:
+//!
+//! - <https://en.wikipedia.org/wiki/Durand%E2%80%93Kerner_method>
+//! - <https://en.wikipedia.org/wiki/Aberth_method>
+//! - D.A. Bini, Numerical computation of polynomial zeros by means of Aberth's method,
+//!   Numerical Algorithms 13, 1996
+
+use num_complex::Complex;
+use crate::function::complex::abs;
+
+/// Newton steps to polish each root
+const POLISH_ITERATIONS: usize = 3;
+
+/// Evaluate polynomial and its derivative with Horner's method
+fn horner(c: &[Complex<f64>], z: Complex<f64>) -> (Complex<f64>, Complex<f64>) {
+    let mut p = c[c.len() - 1];
+    let mut dp = Complex::new(0.0, 0.0);
+
+    for ci in c.iter().rev().skip(1) {
+        dp = dp * z + p;
+        p = p * z + ci;
+    }
+    (p, dp)
+}
+
+/// Coefficients without highest degree zeros and number of zero roots
+fn prepare<C: Into<Complex<f64>> + Copy>(coeffs: &[C]) -> (Vec<Complex<f64>>, usize) {
+    let zero = Complex::new(0.0, 0.0);
+    let mut c: Vec<Complex<f64>> = coeffs.iter().map(|&c| c.into()).collect();
+
+    while c.last() == Some(&zero) {
+        c.pop();
+    }
+
+    let nr_zero_roots = c.iter().take_while(|&&c| c == zero).count();
+    c.drain(..nr_zero_roots.min(c.len()));
+
+    (c, nr_zero_roots)
+}
+
+/// Initial estimates on a circle around the centroid of the roots
+///
+/// Radius is Fujiwara bound of the roots, angles are shifted
+/// to break symmetry of real coefficients.
+fn initial_estimates(c: &[Complex<f64>]) -> Vec<Complex<f64>> {
+    let n = c.len() - 1;
+    let lead = c[n];
+    let center = -c[n - 1] / (lead * n as f64);
+
+    let radius = (1..=n).map(|k| {
+        let r = abs(c[n - k] / lead);
+        if k == n { (r / 2.0).powf(1.0 / k as f64) } else { r.powf(1.0 / k as f64) }
+    }).fold(0.0f64, f64::max) * 2.0;
+    let radius = if radius > 0.0 { radius } else { 1.0 };
+
+    (0..n).map(|k| {
+        let angle = 2.0 * std::f64::consts::PI * k as f64 / n as f64 + 0.4;
+        center + Complex::from_polar(radius, angle)
+    }).collect()
+}
+
+/// Newton steps on the original coefficients, a step is accepted only if it reduces `|P(z)|`
+fn polish(c: &[Complex<f64>], roots: &mut [Complex<f64>]) {
+    for z in roots.iter_mut() {
+        for _ in 0..POLISH_ITERATIONS {
+            let (p, dp) = horner(c, *z);
+            if p == Complex::new(0.0, 0.0) {
+                break;
+            }
+            let z_next = *z - p / dp;
+            if !(z_next.re.is_finite() && z_next.im.is_finite()) ||
+                abs(horner(c, z_next).0) >= abs(p)
+            {
+                break;
+            }
+            *z = z_next;
+        }
+    }
+}
+
+/// Run simultaneous iterations with the correction function
+fn solve<C, S>(
+    coeffs: &[C],
+    epsilon: f64,
+    max_iterations: usize,
+    mut correction: S,
+) -> Vec<Complex<f64>>
+where
+    C: Into<Complex<f64>> + Copy,
+    S: FnMut(&[Complex<f64>], &[Complex<f64>], usize) -> Complex<f64>,
+{
+    let (c, nr_zero_roots) = prepare(coeffs);
+    let mut roots = vec![Complex::new(0.0, 0.0); nr_zero_roots];
+
+    if c.len() < 2 {
+        return roots;
+    }
+
+    if c.len() == 2 {
+        roots.push(-c[0] / c[1]);
+        return roots;
+    }
+
+    let mut z = initial_estimates(&c);
+
+    for _ in 0..max_iterations {
+        let mut converged = true;
+        // Gauss-Seidel style, new estimates are used as soon as computed
+        for k in 0..z.len() {
+            let dz = correction(&c, &z, k);
+            if !(dz.re.is_finite() && dz.im.is_finite()) {
+                continue;
+            }
+            z[k] -= dz;
+            if abs(dz) > epsilon * abs(z[k]).max(1.0) {
+                converged = false;
+            }
+        }
+        if converged {
+            break;
+        }
+    }
+
+    polish(&c, &mut z);
+
+    roots.append(&mut z);
+    roots
+}
+
+/// All complex roots of polynomial `c0 + c1*z + c2*z^2 + ...` with Durand-Kerner method
+///
+/// `z_k -= P(z_k) / (c_n * prod(z_k - z_j, j != k))`
+///
+/// Iterations stop when all corrections are smaller than `epsilon * max(|z_k|, 1)`
+/// or after `max_iterations`, then roots are polished with Newton steps.
+/// Coefficients can be real or complex. Convergence is linear near multiple roots.
+///
+/// # Example
+///
+/// ```
+/// # use rustamath::polynomial::roots::simultaneous::durand_kerner;
+/// # use assert_float_eq::*;
+/// // z^2 + 1
+/// let mut roots = durand_kerner(&[1.0, 0.0, 1.0], 1.0e-15, 100);
+/// roots.sort_by(|a, b| a.im.partial_cmp(&b.im).unwrap());
+/// assert_float_absolute_eq!(roots[0].im, -1.0, 1.0e-15);
+/// assert_float_absolute_eq!(roots[1].im, 1.0, 1.0e-15);
+/// ```
+pub fn durand_kerner<C: Into<Complex<f64>> + Copy>(
+    coeffs: &[C],
+    epsilon: f64,
+    max_iterations: usize,
+) -> Vec<Complex<f64>> {
+    solve(coeffs, epsilon, max_iterations, |c, z, k| {
+        let (p, _) = horner(c, z[k]);
+        let den = z.iter().enumerate()
+            .filter(|&(j, _)| j != k)
+            .fold(c[c.len() - 1], |den, (_, zj)| den * (z[k] - zj));
+        p / den
+    })
+}
+
+/// All complex roots of polynomial `c0 + c1*z + c2*z^2 + ...` with Aberth-Ehrlich method
+///
+/// `w = P(z_k)/P'(z_k)`, `z_k -= w / (1 - w * sum(1/(z_k - z_j), j != k))`
+///
+/// Converges cubically to simple roots, usually faster than [`durand_kerner`].
+/// Iterations stop when all corrections are smaller than `epsilon * max(|z_k|, 1)`
+/// or after `max_iterations`, then roots are polished with Newton steps.
+///
+/// # Example
+///
+/// ```
+/// # use rustamath::polynomial::roots::simultaneous::aberth_ehrlich;
+/// # use num_complex::Complex;
+/// // poles of 1/(s^3 + 2s^2 + 2s + 1) = 1/((s + 1)(s^2 + s + 1))
+/// let roots = aberth_ehrlich(&[1.0, 2.0, 2.0, 1.0], 1.0e-15, 100);
+/// assert_eq!(roots.len(), 3);
+/// assert!(roots.iter().all(|s| s.re < 0.0));
+/// assert!(roots.iter().any(|s| (s - Complex::new(-1.0, 0.0)).norm() < 1.0e-14));
+/// ```
+pub fn aberth_ehrlich<C: Into<Complex<f64>> + Copy>(
+    coeffs: &[C],
+    epsilon: f64,
+    max_iterations: usize,
+) -> Vec<Complex<f64>> {
+    solve(coeffs, epsilon, max_iterations, |c, z, k| {
+        let (p, dp) = horner(c, z[k]);
+        if p == Complex::new(0.0, 0.0) {
+            return p;
+        }
+        let w = p / dp;
+        let sum = z.iter().enumerate()
+            .filter(|&(j, _)| j != k)
+            .fold(Complex::new(0.0, 0.0), |sum, (_, zj)| sum + 1.0 / (z[k] - zj));
+        w / (1.0 - w * sum)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::polynomial::test_helpers::{from_roots, check_roots};
+
+    type Solver = fn(&[Complex<f64>], f64, usize) -> Vec<Complex<f64>>;
+
+    fn test_solver(name: &str, solver: Solver) {
+        let c = |re: f64, im: f64| Complex::new(re, im);
+
+        // roots of unity
+        let n = 12;
+        let expected: Vec<_> = (0..n)
+            .map(|k| Complex::from_polar(1.0, 2.0 * std::f64::consts::PI * k as f64 / n as f64))
+            .collect();
+        let mut coeffs = vec![c(0.0, 0.0); n + 1];
+        coeffs[0] = c(-1.0, 0.0);
+        coeffs[n] = c(1.0, 0.0);
+        check_roots(name, &solver(&coeffs, 1.0e-15, 500), &expected, 1.0e-14);
+
+        // complex coefficients, zero root and zero leading coefficients
+        let expected = [c(0.0, 1.0), c(0.0, -2.0), c(1.0, 1.0), c(-3.5, 0.25), c(0.0, 0.0)];
+        let mut coeffs = from_roots(&expected).into_coeffs();
+        coeffs.push(c(0.0, 0.0));
+        check_roots(name, &solver(&coeffs, 1.0e-15, 500), &expected, 1.0e-13);
+
+        // Wilkinson polynomial of degree 10
+        let expected: Vec<_> = (1..=10).map(|k| c(k as f64, 0.0)).collect();
+        let coeffs = from_roots(&expected).into_coeffs();
+        check_roots(name, &solver(&coeffs, 1.0e-15, 500), &expected, 1.0e-8);
+
+        // Butterworth filter of order 8, poles on the left half of the unit circle
+        let expected: Vec<_> = (0..8)
+            .map(|k| Complex::from_polar(1.0,
+                std::f64::consts::PI * (2.0 * k as f64 + 9.0) / 16.0))
+            .collect();
+        let found = solver(&from_roots(&expected).into_coeffs(), 1.0e-15, 500);
+        check_roots(name, &found, &expected, 1.0e-12);
+        assert!(found.iter().all(|s| s.re < 0.0));
+
+        // triple root converges slowly and only to cube root of precision
+        let expected = [c(1.0, 0.0), c(1.0, 0.0), c(1.0, 0.0), c(-2.0, 0.0)];
+        let coeffs = from_roots(&expected).into_coeffs();
+        check_roots(name, &solver(&coeffs, 1.0e-15, 1000), &expected, 1.0e-4);
+
+        assert!(solver(&[c(5.0, 0.0)], 1.0e-15, 100).is_empty());
+        check_roots(name, &solver(&[c(2.0, 0.0), c(4.0, 0.0)], 1.0e-15, 100), &[c(-0.5, 0.0)],
+            1.0e-15);
+    }
+
+    #[test]
+    fn durand_kerner_roots() {
+        test_solver("Durand-Kerner", durand_kerner);
+    }
+
+    #[test]
+    fn aberth_ehrlich_roots() {
+        test_solver("Aberth-Ehrlich", aberth_ehrlich);
+
+        // real coefficients
+        let roots = aberth_ehrlich(&[-6.0, 11.0, -6.0, 1.0], 1.0e-15, 100);
+        check_roots("real", &roots, &[Complex::new(1.0, 0.0), Complex::new(2.0, 0.0),
+            Complex::new(3.0, 0.0)], 1.0e-14);
+    }
+}
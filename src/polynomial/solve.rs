@@ -0,0 +1,568 @@
+//! Closed-form solvers of quadratic, cubic and quartic equations.
+//!
+//! (c) Igor Lesik 2023
+//! MIT license
+//!
+//! Same conventions as GSL `gsl_poly_solve_*` and `gsl_poly_complex_solve_*`:
+//! quadratic is `a*x^2 + b*x + c`, cubic and quartic are monic, roots are sorted.
+//! Roots are returned in fixed size [`Solutions`] without heap allocation.
+//!
+//! Cancellation is avoided with the citardauq form of the quadratic formula
+//! and Kahan's discriminant computed with FMA, real roots of cubic and quartic
+//! are polished with a Newton step.
+//!
+//! References:
+//!
+//! - <https://www.gnu.org/software/gsl/doc/html/poly.html#quadratic-equations>
+//! - <https://en.wikipedia.org/wiki/Quadratic_formula#Square_root_in_the_denominator>
+//! - W. Kahan, On the Cost of Floating-Point Computation Without Extra-Precise Arithmetic, 2004
+//! - <https://en.wikipedia.org/wiki/Cubic_equation#Trigonometric_and_hyperbolic_solutions>
+//! - <https://en.wikipedia.org/wiki/Quartic_equation#Ferrari's_solution>
+
+use std::ops::Deref;
+use num_complex::Complex;
+use crate::function::mul_add;
+
+/// Up to `N` roots stored inline, dereferences to slice of found roots
+///
+/// # Example
+///
+/// ```
+/// # use rustamath::polynomial::*;
+/// // x^2 - 3x + 2 = (x - 1)(x - 2)
+/// let roots = solve_quadratic(1.0, -3.0, 2.0);
+/// assert_eq!(roots.len(), 2);
+/// assert_eq!(&roots[..], &[1.0, 2.0]);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Solutions<T, const N: usize> {
+    roots: [T; N],
+    len: usize,
+}
+
+impl<T: Copy + Default, const N: usize> Solutions<T, N> {
+    fn new() -> Self {
+        Solutions {roots: [T::default(); N], len: 0}
+    }
+
+    fn push(&mut self, x: T) {
+        self.roots[self.len] = x;
+        self.len += 1;
+    }
+}
+
+impl<T, const N: usize> Deref for Solutions<T, N> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        &self.roots[..self.len]
+    }
+}
+
+/// Discriminant `b^2 - 4ac` with Kahan's FMA trick, accurate even when `b^2 ~ 4ac`
+///
+/// # Example
+///
+/// ```
+/// # use rustamath::polynomial::*;
+/// // b^2 = 1 + 2^-26 + 2^-54 is rounded, 4ac = 1 + 2^-26
+/// let (a, b, c) = (0.25, 1.0 + 2f64.powi(-27), 1.0 + 2f64.powi(-26));
+/// assert_eq!(discriminant(a, b, c), 2f64.powi(-54));
+/// assert_eq!(b*b - 4.0*a*c, 0.0);
+/// ```
+pub fn discriminant(a: f64, b: f64, c: f64) -> f64 {
+    let w = 4.0 * a * c;
+    // exact rounding error of 4ac and rounded b^2 - w
+    let e = mul_add(-4.0 * a, c, w);
+    let f = mul_add(b, b, -w);
+    f + e
+}
+
+/// Real roots of `a*x^2 + b*x + c` in ascending order
+///
+/// Double root is returned twice, if `a = 0` the root of the linear equation is returned,
+/// no roots if all coefficients are zero. Roots are `q/a` and `c/q`
+/// with `q = -(b + sgn(b)*sqrt(D))/2`, so there is no subtraction of close values.
+///
+/// # Example
+///
+/// ```
+/// # use rustamath::polynomial::*;
+/// // tiny root would be lost by -b + sqrt(b^2 - 4ac)
+/// let roots = solve_quadratic(1.0, 1.0e8, 1.0);
+/// assert_eq!(roots[0], -1.0e8);
+/// assert_eq!(roots[1], -1.0e-8);
+/// assert!(solve_quadratic(1.0, 0.0, 1.0).is_empty());
+/// ```
+pub fn solve_quadratic(a: f64, b: f64, c: f64) -> Solutions<f64, 2> {
+    let mut roots = Solutions::new();
+
+    if a == 0.0 {
+        if b != 0.0 {
+            roots.push(-c / b);
+        }
+        return roots;
+    }
+
+    let d = discriminant(a, b, c);
+
+    if d < 0.0 {
+        return roots;
+    }
+
+    if d == 0.0 {
+        let r = -0.5 * b / a;
+        roots.push(r);
+        roots.push(r);
+        return roots;
+    }
+
+    let q = -0.5 * (b + b.signum() * d.sqrt());
+    let (r1, r2) = (q / a, c / q);
+
+    roots.push(r1.min(r2));
+    roots.push(r1.max(r2));
+    roots
+}
+
+/// Complex roots of `a*x^2 + b*x + c` sorted by real then imaginary part
+///
+/// Same as [`solve_quadratic`], complex conjugate roots are returned when discriminant
+/// is negative.
+///
+/// # Example
+///
+/// ```
+/// # use rustamath::polynomial::*;
+/// # use num_complex::Complex;
+/// let roots = solve_quadratic_complex(1.0, 2.0, 5.0);
+/// assert_eq!(&roots[..], &[Complex::new(-1.0, -2.0), Complex::new(-1.0, 2.0)]);
+/// ```
+pub fn solve_quadratic_complex(a: f64, b: f64, c: f64) -> Solutions<Complex<f64>, 2> {
+    let mut roots = Solutions::new();
+
+    if a != 0.0 {
+        let d = discriminant(a, b, c);
+        if d < 0.0 {
+            let re = -0.5 * b / a;
+            let im = (0.5 * (-d).sqrt() / a).abs();
+            roots.push(Complex::new(re, -im));
+            roots.push(Complex::new(re, im));
+            return roots;
+        }
+    }
+
+    for x in solve_quadratic(a, b, c).iter() {
+        roots.push(Complex::new(*x, 0.0));
+    }
+    roots
+}
+
+/// One Newton step for root of monic polynomial `x^n + c[n-1]*x^(n-1) + ... + c[0]`,
+/// accepted only if it reduces the residual
+fn polish(x: f64, c: &[f64]) -> f64 {
+    let eval = |x: f64| {
+        c.iter().rev().fold((1.0, 0.0), |(p, dp), ci| (mul_add(p, x, *ci), mul_add(dp, x, p)))
+    };
+    let (p, dp) = eval(x);
+    if p == 0.0 || dp == 0.0 {
+        return x;
+    }
+    let next = x - p / dp;
+    if next.is_finite() && eval(next).0.abs() < p.abs() { next } else { x }
+}
+
+/// Depressed cubic invariants of `x^3 + a*x^2 + b*x + c` as `(Q, R)`, GSL notation
+fn cubic_q_r(a: f64, b: f64, c: f64) -> (f64, f64) {
+    let q = a * a - 3.0 * b;
+    let r = 2.0 * a * a * a - 9.0 * a * b + 27.0 * c;
+    (q / 9.0, r / 54.0)
+}
+
+/// Real roots of `x^3 + a*x^2 + b*x + c` in ascending order
+///
+/// Multiple roots are returned as many times as their multiplicity.
+/// Three real roots are found with the trigonometric method,
+/// a single real root with Cardano's formula.
+///
+/// # Example
+///
+/// ```
+/// # use rustamath::polynomial::*;
+/// # use assert_float_eq::*;
+/// // (x - 1)(x - 2)(x - 3)
+/// let roots = solve_cubic(-6.0, 11.0, -6.0);
+/// assert_eq!(roots.len(), 3);
+/// assert_float_absolute_eq!(roots[0], 1.0, 1.0e-15);
+/// assert_float_absolute_eq!(roots[1], 2.0, 1.0e-15);
+/// assert_float_absolute_eq!(roots[2], 3.0, 1.0e-15);
+/// // (x - 1)(x^2 + 1)
+/// assert_eq!(&solve_cubic(-1.0, 1.0, -1.0)[..], &[1.0]);
+/// ```
+pub fn solve_cubic(a: f64, b: f64, c: f64) -> Solutions<f64, 3> {
+    let mut roots = Solutions::new();
+    let coeffs = [c, b, a];
+
+    let (q, r) = cubic_q_r(a, b, c);
+    let q3 = q * q * q;
+    let r2 = r * r;
+    let shift = a / 3.0;
+
+    if r == 0.0 && q == 0.0 {
+        // triple root
+        for _ in 0..3 {
+            roots.push(-shift);
+        }
+    } else if r2 == q3 {
+        // double root, r2 == q3 implies q > 0
+        let sqrt_q = q.sqrt();
+        let (single, double) = if r > 0.0 {
+            (-2.0 * sqrt_q - shift, sqrt_q - shift)
+        } else {
+            (2.0 * sqrt_q - shift, -sqrt_q - shift)
+        };
+        let single = polish(single, &coeffs);
+        if single < double {
+            roots.push(single);
+            roots.push(double);
+            roots.push(double);
+        } else {
+            roots.push(double);
+            roots.push(double);
+            roots.push(single);
+        }
+    } else if r2 < q3 {
+        // three real roots
+        let ratio = (r / q3.sqrt()).clamp(-1.0, 1.0);
+        let theta = ratio.acos();
+        let norm = -2.0 * q.sqrt();
+        let two_pi = 2.0 * std::f64::consts::PI;
+        let x = [
+            norm * (theta / 3.0).cos() - shift,
+            norm * ((theta + two_pi) / 3.0).cos() - shift,
+            norm * ((theta - two_pi) / 3.0).cos() - shift,
+        ];
+        // smaller roots lose accuracy, take them from the deflated quadratic
+        let x0 = polish(x.into_iter().fold(0.0f64, |m, x| if x.abs() > m.abs() { x } else { m }),
+            &coeffs);
+        let mut x = x;
+        if x0 != 0.0 {
+            let q = -c / x0;
+            let others = solve_quadratic(1.0, (q - b) / x0, q);
+            if others.len() == 2 {
+                x = [x0, others[0], others[1]];
+            }
+        }
+        let mut x = x.map(|x| polish(x, &coeffs));
+        x.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+        for xi in x {
+            roots.push(xi);
+        }
+    } else {
+        // one real root
+        let big_a = -r.signum() * (r.abs() + (r2 - q3).sqrt()).cbrt();
+        let big_b = if big_a != 0.0 { q / big_a } else { 0.0 };
+        roots.push(polish(big_a + big_b - shift, &coeffs));
+    }
+
+    roots
+}
+
+/// Complex roots of `x^3 + a*x^2 + b*x + c` sorted by real then imaginary part
+///
+/// Same as [`solve_cubic`], the complex conjugate pair is returned
+/// when there is a single real root.
+///
+/// # Example
+///
+/// ```
+/// # use rustamath::polynomial::*;
+/// # use assert_float_eq::*;
+/// // x^3 - 1
+/// let roots = solve_cubic_complex(0.0, 0.0, -1.0);
+/// assert_float_absolute_eq!(roots[0].re, -0.5, 1.0e-15);
+/// assert_float_absolute_eq!(roots[0].im, -0.75f64.sqrt(), 1.0e-15);
+/// assert_float_absolute_eq!(roots[1].im, 0.75f64.sqrt(), 1.0e-15);
+/// assert_eq!(roots[2].re, 1.0);
+/// ```
+pub fn solve_cubic_complex(a: f64, b: f64, c: f64) -> Solutions<Complex<f64>, 3> {
+    let mut roots = Solutions::new();
+
+    let (q, r) = cubic_q_r(a, b, c);
+    let q3 = q * q * q;
+    let r2 = r * r;
+
+    if r2 <= q3 || (r == 0.0 && q == 0.0) {
+        // all roots are real
+        for x in solve_cubic(a, b, c).iter() {
+            roots.push(Complex::new(*x, 0.0));
+        }
+        return roots;
+    }
+
+    let shift = a / 3.0;
+    let big_a = -r.signum() * (r.abs() + (r2 - q3).sqrt()).cbrt();
+    let big_b = if big_a != 0.0 { q / big_a } else { 0.0 };
+
+    let x = polish(big_a + big_b - shift, &[c, b, a]);
+    // other roots from deflated quadratic x^2 + (a + x)*y + (b + (a + x)*x)
+    let (re, im) = if x != 0.0 {
+        let p = a + x;
+        (-0.5 * p, 0.5 * (-discriminant(1.0, p, -c / x)).max(0.0).sqrt())
+    } else {
+        (-0.5 * (big_a + big_b) - shift, 0.75f64.sqrt() * (big_a - big_b).abs())
+    };
+
+    let mut z = [Complex::new(re, -im), Complex::new(re, im), Complex::new(x, 0.0)];
+    z.sort_by(|u, v| (u.re, u.im).partial_cmp(&(v.re, v.im)).unwrap_or(std::cmp::Ordering::Equal));
+    for zi in z {
+        roots.push(zi);
+    }
+    roots
+}
+
+/// Depressed quartic `y^4 + p*y^2 + q*y + r` with `x = y - a/4` factored into quadratics
+enum QuarticSplit {
+    /// `z^2 + p*z + r` with `z = y^2`
+    Biquadratic(f64, f64),
+    /// `y^2 + s*y + (m - t)` and `y^2 - s*y + (m + t)`
+    Quadratics(f64, f64, f64),
+}
+
+/// Split `x^4 + a*x^3 + b*x^2 + c*x + d` with Ferrari's method
+fn split_quartic(a: f64, b: f64, c: f64, d: f64) -> QuarticSplit {
+    let a2 = a * a;
+    let p = b - 3.0 / 8.0 * a2;
+    let q = c - 0.5 * a * b + a2 * a / 8.0;
+    let r = d - 0.25 * a * c + a2 * b / 16.0 - 3.0 / 256.0 * a2 * a2;
+
+    // q is zero up to rounding of the terms it is made of
+    let q_scale = c.abs() + 0.5 * (a * b).abs() + (a2 * a).abs() / 8.0;
+    if q.abs() <= 4.0 * f64::EPSILON * q_scale {
+        return QuarticSplit::Biquadratic(p, r);
+    }
+
+    // resolvent cubic m^3 - p/2*m^2 - r*m + (p*r/2 - q^2/8),
+    // its largest root has 2m - p > 0 when q is not zero
+    let resolvent = solve_cubic(-0.5 * p, -r, 0.5 * p * r - q * q / 8.0);
+    let m = resolvent[resolvent.len() - 1];
+    let s = (2.0 * m - p).max(0.0).sqrt();
+
+    if s == 0.0 {
+        return QuarticSplit::Biquadratic(p, r);
+    }
+
+    // (y^2 + m)^2 = (s*y - q/(2s))^2
+    QuarticSplit::Quadratics(s, m, 0.5 * q / s)
+}
+
+/// Real roots of `x^4 + a*x^3 + b*x^2 + c*x + d` in ascending order
+///
+/// Ferrari's method, depressed quartic is split into two quadratics
+/// with the largest root of the resolvent cubic.
+///
+/// # Example
+///
+/// ```
+/// # use rustamath::polynomial::*;
+/// # use assert_float_eq::*;
+/// // (x^2 - 1)(x^2 - 4)
+/// let roots = solve_quartic(0.0, -5.0, 0.0, 4.0);
+/// assert_eq!(&roots[..], &[-2.0, -1.0, 1.0, 2.0]);
+/// // (x - 1)(x - 2)(x^2 + 1)
+/// let roots = solve_quartic(-3.0, 3.0, -3.0, 2.0);
+/// assert_eq!(roots.len(), 2);
+/// assert_float_absolute_eq!(roots[0], 1.0, 1.0e-15);
+/// assert_float_absolute_eq!(roots[1], 2.0, 1.0e-15);
+/// ```
+pub fn solve_quartic(a: f64, b: f64, c: f64, d: f64) -> Solutions<f64, 4> {
+    let mut roots = Solutions::new();
+
+    if d == 0.0 {
+        // zero root and cubic
+        roots.push(0.0);
+        for x in solve_cubic(a, b, c).iter() {
+            roots.push(*x);
+        }
+    } else {
+        let coeffs = [d, c, b, a];
+        let mut push = |y: f64| roots.push(polish(y - 0.25 * a, &coeffs));
+
+        match split_quartic(a, b, c, d) {
+            QuarticSplit::Biquadratic(p, r) => {
+                for z in solve_quadratic(1.0, p, r).iter().filter(|z| **z >= 0.0) {
+                    push(-z.sqrt());
+                    push(z.sqrt());
+                }
+            }
+            QuarticSplit::Quadratics(s, m, t) => {
+                for y in solve_quadratic(1.0, s, m - t).iter() {
+                    push(*y);
+                }
+                for y in solve_quadratic(1.0, -s, m + t).iter() {
+                    push(*y);
+                }
+            }
+        }
+    }
+
+    roots.roots[..roots.len].sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+    roots
+}
+
+/// Complex roots of `x^4 + a*x^3 + b*x^2 + c*x + d` sorted by real then imaginary part
+///
+/// Same as [`solve_quartic`], complex roots of the quadratics are included.
+///
+/// # Example
+///
+/// ```
+/// # use rustamath::polynomial::*;
+/// # use assert_float_eq::*;
+/// // x^4 + 4 = (x^2 + 2x + 2)(x^2 - 2x + 2), roots are -1-i, -1+i, 1-i, 1+i
+/// let roots = solve_quartic_complex(0.0, 0.0, 0.0, 4.0);
+/// for (z, (re, im)) in roots.iter().zip([(-1.0, -1.0), (-1.0, 1.0), (1.0, -1.0), (1.0, 1.0)]) {
+///     assert_float_absolute_eq!(z.re, re, 1.0e-15);
+///     assert_float_absolute_eq!(z.im, im, 1.0e-15);
+/// }
+/// ```
+pub fn solve_quartic_complex(a: f64, b: f64, c: f64, d: f64) -> Solutions<Complex<f64>, 4> {
+    let mut roots = Solutions::new();
+
+    if d == 0.0 {
+        roots.push(Complex::new(0.0, 0.0));
+        for z in solve_cubic_complex(a, b, c).iter() {
+            roots.push(*z);
+        }
+    } else {
+        let coeffs = [d, c, b, a];
+        let mut push = |y: Complex<f64>| {
+            let x = y - 0.25 * a;
+            roots.push(if x.im == 0.0 { Complex::new(polish(x.re, &coeffs), 0.0) } else { x });
+        };
+
+        match split_quartic(a, b, c, d) {
+            QuarticSplit::Biquadratic(p, r) => {
+                for z in solve_quadratic_complex(1.0, p, r).iter() {
+                    let y = if z.im == 0.0 && z.re >= 0.0 {
+                        Complex::new(z.re.sqrt(), 0.0)
+                    } else if z.im == 0.0 {
+                        Complex::new(0.0, (-z.re).sqrt())
+                    } else {
+                        z.sqrt()
+                    };
+                    push(-y);
+                    push(y);
+                }
+            }
+            QuarticSplit::Quadratics(s, m, t) => {
+                for y in solve_quadratic_complex(1.0, s, m - t).iter() {
+                    push(*y);
+                }
+                for y in solve_quadratic_complex(1.0, -s, m + t).iter() {
+                    push(*y);
+                }
+            }
+        }
+    }
+
+    roots.roots[..roots.len].sort_by(|u, v| {
+        (u.re, u.im).partial_cmp(&(v.re, v.im)).unwrap_or(std::cmp::Ordering::Equal)
+    });
+    roots
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::polynomial::test_helpers::{from_roots, check_real_roots};
+
+    /// Monic polynomial coefficients from highest degree down, without leading 1
+    fn monic(roots: &[f64]) -> Vec<f64> {
+        from_roots(roots).into_coeffs().into_iter().rev().skip(1).collect()
+    }
+
+    #[test]
+    fn quadratic() {
+        check_real_roots("distinct", &solve_quadratic(2.0, -2.0, -12.0), &[-2.0, 3.0], 1.0e-15);
+        check_real_roots("double", &solve_quadratic(1.0, -2.0, 1.0), &[1.0, 1.0], 0.0);
+        check_real_roots("linear", &solve_quadratic(0.0, 2.0, -3.0), &[1.5], 0.0);
+        check_real_roots("zero root", &solve_quadratic(1.0, -4.0, 0.0), &[0.0, 4.0], 0.0);
+        assert!(solve_quadratic(0.0, 0.0, 1.0).is_empty());
+        assert!(solve_quadratic(1.0, 1.0, 1.0).is_empty());
+
+        // cancellation in b^2 - 4ac, roots 1 +/- 2^-26
+        let e = 2.0f64.powi(-26);
+        check_real_roots("close", &solve_quadratic(1.0, -2.0, 1.0 - e * e), &[1.0 - e, 1.0 + e], 1.0e-15);
+        // naive formula gives a double root
+        assert_eq!(4.0 - 4.0 * (1.0 - e * e), 4.0 * e * e);
+        assert_eq!(discriminant(1.0, -2.0, 1.0 - e * e), 4.0 * e * e);
+
+        let z = solve_quadratic_complex(4.0, -4.0, 5.0);
+        assert_eq!(&z[..], &[Complex::new(0.5, -1.0), Complex::new(0.5, 1.0)]);
+        let z = solve_quadratic_complex(1.0, -3.0, 2.0);
+        assert_eq!(&z[..], &[Complex::new(1.0, 0.0), Complex::new(2.0, 0.0)]);
+        assert_eq!(solve_quadratic_complex(0.0, 1.0, 1.0).len(), 1);
+    }
+
+    #[test]
+    fn cubic() {
+        for expected in [
+            [1.0, 2.0, 3.0],
+            [-1.0e3, 1.0e-3, 7.0],
+            [-2.0, -2.0, 5.0],
+            [1.0, 4.0, 4.0],
+            [0.5, 0.5, 0.5],
+            [0.0, 1.0, 1.0e6],
+        ] {
+            let c = monic(&expected);
+            check_real_roots("cubic", &solve_cubic(c[0], c[1], c[2]), &expected, 1.0e-12);
+            let z: Vec<f64> = solve_cubic_complex(c[0], c[1], c[2]).iter().map(|z| z.re).collect();
+            check_real_roots("complex cubic", &z, &expected, 1.0e-12);
+        }
+
+        // one real root, (x + 2)(x^2 - 2x + 5) = x^3 + x + 10
+        check_real_roots("one real", &solve_cubic(0.0, 1.0, 10.0), &[-2.0], 1.0e-15);
+        let z = solve_cubic_complex(0.0, 1.0, 10.0);
+        assert_eq!(z.len(), 3);
+        for (z, (re, im)) in z.iter().zip([(-2.0, 0.0), (1.0, -2.0), (1.0, 2.0)]) {
+            assert_float_absolute_eq!(z.re, re, 1.0e-14);
+            assert_float_absolute_eq!(z.im, im, 1.0e-14);
+        }
+    }
+
+    #[test]
+    fn quartic() {
+        for expected in [
+            [1.0, 2.0, 3.0, 4.0],
+            [-3.0, -1.0, 0.5, 10.0],
+            [-1.0, -1.0, 2.0, 2.0],
+            [0.0, 1.0, 2.0, 3.0],
+            [1.0, 1.0, 1.0, 1.0],
+            [-1.0e-3, 1.0e-2, 5.0, 1.0e3],
+        ] {
+            let c = monic(&expected);
+            let eps = if expected[0] == expected[3] { 1.0e-3 } else { 1.0e-9 };
+            check_real_roots("quartic", &solve_quartic(c[0], c[1], c[2], c[3]), &expected, eps);
+            let z: Vec<f64> = solve_quartic_complex(c[0], c[1], c[2], c[3]).iter()
+                .map(|z| z.re).collect();
+            check_real_roots("complex quartic", &z, &expected, eps);
+        }
+
+        // no real roots, (x^2 + 1)(x^2 + 4)
+        assert!(solve_quartic(0.0, 5.0, 0.0, 4.0).is_empty());
+        let z = solve_quartic_complex(0.0, 5.0, 0.0, 4.0);
+        for (z, im) in z.iter().zip([-2.0, -1.0, 1.0, 2.0]) {
+            assert_float_absolute_eq!(z.re, 0.0, 1.0e-15);
+            assert_float_absolute_eq!(z.im, im, 1.0e-15);
+        }
+
+        // (x - 1)(x + 2)(x^2 + 2x + 5) = x^4 + 3x^3 + 5x^2 + x - 10
+        check_real_roots("two real", &solve_quartic(3.0, 5.0, 1.0, -10.0), &[-2.0, 1.0], 1.0e-14);
+        let z = solve_quartic_complex(3.0, 5.0, 1.0, -10.0);
+        for (z, (re, im)) in z.iter().zip([(-2.0, 0.0), (-1.0, -2.0), (-1.0, 2.0), (1.0, 0.0)]) {
+            assert_float_absolute_eq!(z.re, re, 1.0e-14);
+            assert_float_absolute_eq!(z.im, im, 1.0e-14);
+        }
+    }
+}
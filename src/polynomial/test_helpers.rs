@@ -0,0 +1,34 @@
+//! Helpers shared by polynomial tests.
+//!
+//! (c) Igor Lesik 2023
+//! MIT license
+
+use std::ops::Neg;
+use num_complex::Complex;
+use num_traits::Num;
+use crate::function::complex::abs;
+use super::Polynomial;
+
+/// Product of `(x - r)` for all roots, real or complex
+pub fn from_roots<T: Num + Clone + Neg<Output = T>>(roots: &[T]) -> Polynomial<T> {
+    roots.iter().fold(Polynomial::constant(T::one()), |p, r| {
+        p * Polynomial::new(vec![-r.clone(), T::one()])
+    })
+}
+
+/// Every expected root has a found root nearby
+pub fn check_roots(msg: &str, found: &[Complex<f64>], expected: &[Complex<f64>], eps: f64) {
+    assert_eq!(found.len(), expected.len(), "{}", msg);
+    for e in expected {
+        let nearest = found.iter().map(|f| abs(f - e)).fold(f64::INFINITY, f64::min);
+        assert!(nearest < eps, "{} root {} error {}", msg, e, nearest);
+    }
+}
+
+/// Sorted real roots match expected ones, relative error for roots larger than 1
+pub fn check_real_roots(msg: &str, found: &[f64], expected: &[f64], eps: f64) {
+    assert_eq!(found.len(), expected.len(), "{} {:?}", msg, found);
+    for (x, e) in found.iter().zip(expected) {
+        assert!((x - e).abs() <= eps * e.abs().max(1.0), "{} {} != {}", msg, x, e);
+    }
+}
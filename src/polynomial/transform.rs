@@ -0,0 +1,218 @@
+//! Polynomial composition, change of variable and Chebyshev basis.
+//!
+//! (c) Igor Lesik 2023
+//! MIT license
+//!
+//! Change of variable `x = s*t + a` is [`Polynomial::scale`] by `s`
+//! followed by [`Polynomial::shift`] by `a`, it maps a polynomial
+//! on interval `[a, a + s]` to `[0, 1]`.
+//!
+//! References:
+//!
+//! - <https://en.wikipedia.org/wiki/Polynomial#Composition>
+//! - <https://en.wikipedia.org/wiki/Taylor_shift>
+//! - <https://en.wikipedia.org/wiki/Chebyshev_polynomials>
+
+use num_traits::Num;
+use super::Polynomial;
+
+impl<T: Num + Clone> Polynomial<T> {
+    /// Composition `P(Q(x))`, Horner's method with polynomial arithmetic
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustamath::polynomial::Polynomial;
+    /// let p = Polynomial::new(vec![1.0, 0.0, 1.0]);  // x^2 + 1
+    /// let q = Polynomial::new(vec![-1.0, 2.0]);      // 2x - 1
+    /// assert_eq!(p.compose(&q).coeffs(), &[2.0, -4.0, 4.0]);
+    /// ```
+    pub fn compose(&self, q: &Polynomial<T>) -> Polynomial<T> {
+        self.coeffs().iter().rev().fold(Polynomial::zero(), |acc, c| {
+            &(&acc * q) + &Polynomial::constant(c.clone())
+        })
+    }
+
+    /// Taylor shift `P(x + a)`
+    ///
+    /// Repeated synthetic division by `(x - a)`, coefficients of the result
+    /// are Taylor coefficients of `P` at `a`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustamath::polynomial::Polynomial;
+    /// let p = Polynomial::new(vec![0.0, 0.0, 1.0]);  // x^2
+    /// assert_eq!(p.shift(1.0).coeffs(), &[1.0, 2.0, 1.0]);
+    /// ```
+    pub fn shift(&self, a: T) -> Polynomial<T> {
+        let mut c = self.coeffs().to_vec();
+        let n = c.len();
+        for i in 0..n {
+            for j in (i..n - 1).rev() {
+                c[j] = c[j].clone() + a.clone() * c[j + 1].clone();
+            }
+        }
+        Polynomial::new(c)
+    }
+
+    /// Scale variable `P(s*x)`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustamath::polynomial::Polynomial;
+    /// let p = Polynomial::new(vec![1.0, 1.0, 1.0]);
+    /// assert_eq!(p.scale(2.0).coeffs(), &[1.0, 2.0, 4.0]);
+    /// ```
+    pub fn scale(&self, s: T) -> Polynomial<T> {
+        let mut sk = T::one();
+        Polynomial::new(self.coeffs().iter().map(|c| {
+            let r = c.clone() * sk.clone();
+            sk = sk.clone() * s.clone();
+            r
+        }).collect())
+    }
+
+    /// Reversed coefficients `x^n * P(1/x)`, roots are reciprocal
+    ///
+    /// Zero roots of `P` reduce the degree of the result.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustamath::polynomial::Polynomial;
+    /// let p = Polynomial::new(vec![1.0, 2.0, 3.0]);
+    /// assert_eq!(p.reverse().coeffs(), &[3.0, 2.0, 1.0]);
+    /// ```
+    pub fn reverse(&self) -> Polynomial<T> {
+        Polynomial::new(self.coeffs().iter().rev().cloned().collect())
+    }
+}
+
+/// Convert coefficients `c0, c1, ...` of `c0 + c1*x + ...` to
+/// coefficients of `a0*T0(x) + a1*T1(x) + ...`
+///
+/// Horner's method with multiplication by `x` in Chebyshev basis,
+/// `x*T0 = T1` and `x*Tk = (T(k+1) + T(k-1))/2`.
+/// Note that [`ChebSeries`](super::chebyshev::ChebSeries) halves the first coefficient.
+///
+/// # Example
+///
+/// ```
+/// # use rustamath::polynomial::transform::*;
+/// // x^2 = (T0 + T2)/2
+/// assert_eq!(monomial_to_chebyshev(&[0.0, 0.0, 1.0]), vec![0.5, 0.0, 0.5]);
+/// ```
+pub fn monomial_to_chebyshev(cs: &[f64]) -> Vec<f64> {
+    let n = cs.len();
+    let mut a = vec![0.0; n];
+
+    for (k, c) in cs.iter().enumerate().rev() {
+        // a = x*a + c, a has terms up to T(n-1-k)
+        let mut xa = vec![0.0; n];
+        for j in 0..n - 1 - k {
+            if j == 0 {
+                xa[1] += a[0];
+            } else {
+                xa[j + 1] += 0.5 * a[j];
+                xa[j - 1] += 0.5 * a[j];
+            }
+        }
+        xa[0] += c;
+        a = xa;
+    }
+    a
+}
+
+/// Convert coefficients of `a0*T0(x) + a1*T1(x) + ...` to `c0, c1, ...` of `c0 + c1*x + ...`
+///
+/// # Example
+///
+/// ```
+/// # use rustamath::polynomial::transform::*;
+/// // T3 = 4x^3 - 3x
+/// assert_eq!(chebyshev_to_monomial(&[0.0, 0.0, 0.0, 1.0]), vec![0.0, -3.0, 0.0, 4.0]);
+/// ```
+pub fn chebyshev_to_monomial(cs: &[f64]) -> Vec<f64> {
+    let n = cs.len();
+    let mut c = vec![0.0; n];
+
+    // T(k+1) = 2x*Tk - T(k-1)
+    let mut t_prev = vec![0.0; n];
+    let mut t = vec![0.0; n];
+    t[0] = 1.0;
+    for (k, ak) in cs.iter().enumerate() {
+        for (ci, ti) in c.iter_mut().zip(&t) {
+            *ci += ak * ti;
+        }
+        let next: Vec<f64> = (0..n).map(|i| {
+            let x_t = if i > 0 { t[i - 1] } else { 0.0 };
+            if k == 0 { x_t } else { 2.0 * x_t - t_prev[i] }
+        }).collect();
+        t_prev = std::mem::replace(&mut t, next);
+    }
+    c
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::polynomial::{polynomial_n, derivatives_polynomial_n};
+    use crate::polynomial::orthogonal::OrthogonalPolynomial;
+
+    #[test]
+    fn transform() {
+        let p = Polynomial::new(vec![1.0, -2.0, 0.5, 3.0]);
+        let q = Polynomial::new(vec![0.5, 1.0, -1.0]);
+
+        let pq = p.compose(&q);
+        assert_eq!(pq.degree(), 6);
+        for x in [-1.5, 0.0, 0.3, 2.0] {
+            assert_float_relative_eq!(pq.eval(x), p.eval(q.eval(x)), 1.0e-13);
+            assert_float_relative_eq!(p.shift(0.7).eval(x), p.eval(x + 0.7), 1.0e-13);
+            assert_float_relative_eq!(p.scale(-1.5).eval(x), p.eval(-1.5 * x), 1.0e-13);
+            if x != 0.0 {
+                assert_float_relative_eq!(p.reverse().eval(x), x.powi(3) * p.eval(1.0 / x), 1.0e-13);
+            }
+        }
+        assert_eq!(p.compose(&Polynomial::new(vec![0.0, 1.0])), p);
+        assert_eq!(Polynomial::constant(2.0).compose(&q), Polynomial::constant(2.0));
+        assert_eq!(p.shift(2.0).shift(-2.0), p);
+        assert_eq!(p.scale(2.0).scale(0.5), p);
+        assert_eq!(p.reverse().reverse(), p);
+        assert_eq!(Polynomial::new(vec![0.0, 1.0, 2.0]).reverse().coeffs(), &[2.0, 1.0]);
+
+        // shift gives Taylor coefficients
+        let mut pd = [0.0; 4];
+        derivatives_polynomial_n(0.7, p.coeffs(), &mut pd);
+        for (k, (s, d)) in p.shift(0.7).coeffs().iter().zip(pd).enumerate() {
+            let factorial: f64 = (1..=k).map(|i| i as f64).product();
+            assert_float_relative_eq!(*s, d / factorial, 1.0e-14);
+        }
+
+        // integer coefficients
+        let p = Polynomial::new(vec![1i64, 1]);
+        assert_eq!(p.compose(&p).coeffs(), &[2, 1]);
+        assert_eq!(Polynomial::new(vec![0i64, 0, 0, 1]).shift(1).coeffs(), &[1, 3, 3, 1]);
+
+        // Chebyshev basis
+        let t = OrthogonalPolynomial::ChebyshevT;
+        let c = [0.3, -1.2, 0.0, 2.5, 0.75];
+        let a = monomial_to_chebyshev(&c);
+        for x in [-0.9, -0.1, 0.4, 1.0] {
+            let y: f64 = a.iter().enumerate().map(|(k, ak)| ak * t.eval(k, x)).sum();
+            assert_float_absolute_eq!(y, polynomial_n(x, &c), 1.0e-14);
+        }
+        for (back, ci) in chebyshev_to_monomial(&a).iter().zip(c) {
+            assert_float_absolute_eq!(*back, ci, 1.0e-14);
+        }
+        for k in 0..8 {
+            let mut e = vec![0.0; k + 1];
+            e[k] = 1.0;
+            assert_eq!(chebyshev_to_monomial(&e), t.coeffs(k));
+        }
+        assert_eq!(monomial_to_chebyshev(&[4.0]), vec![4.0]);
+        assert!(monomial_to_chebyshev(&[]).is_empty());
+    }
+}
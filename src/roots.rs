@@ -1,134 +1,239 @@
 //! Roots finding algorithm
+//!
+//! One dimensional root finders are generic over `num_traits::Float`,
+//! `f64` is the default scalar type.
+
+use num_traits::float::Float;
 
 pub mod bisection;
 pub mod itp;
-//https://github.com/ampl/gsl/blob/master/roots/newton.c
-//https://github.com/ampl/gsl/blob/master/roots/steffenson.c
-//https://github.com/ampl/gsl/blob/master/roots/secant.c
-//https://github.com/ampl/gsl/blob/master/roots/falsepos.c
-//https://github.com/ampl/gsl/blob/master/roots/brent.c
+pub mod newton;
+pub mod brent;
+pub mod secant;
+pub mod falsepos;
+pub mod steffensen;
+pub mod convergence;
+pub mod stepper;
+pub mod bracket;
+pub mod multi;
+pub mod complex;
+
+pub use self::convergence::Convergence;
+pub use self::stepper::{RootStep, RootStepper, DerivRootStepper, RootIterate};
+pub use self::bracket::ScannedRoot;
 
 #[cfg(test)]
 mod tests;
 
-/// Errors that may happen in root finding algorithm
+/// Errors that may happen in root finding algorithm,
+/// `E` is the error type of the function with roots
 #[derive(Debug)]
-pub enum RootsErr {
-    /// Call to the function with roots failed
-    FunctionFailed,
+pub enum RootsErr<E = ()> {
+    /// Call to the function with roots failed with user error
+    FunctionFailed(E),
     /// Input x range with y endpoints that do not straddle y=0
     EndpointsNotStraddleYeq0,
+    /// Derivative is zero or Newton step is not finite
+    ZeroDerivative,
+    /// Convergence criterion has negative tolerance
+    BadTolerance,
+    /// Range expansion did not find sign change
+    BracketNotFound,
+    /// Jacobian matrix is singular
+    SingularJacobian,
+    /// Sizes of vectors or matrices do not match
+    DimensionsMismatch,
+    /// Iterations do not improve the solution
+    NoProgress,
+    /// Input value is NaN or infinite
+    NotFinite,
 }
 
 /// Bisection x and y ranges.
-pub type Range = (f64, f64, f64, f64);
+pub type Range<T = f64> = (T, T, T, T);
 
-/// Function with roots that we investigate
-pub type FnWithRoots = fn(x: f64) -> Result<f64, ()>;
+/// Function with roots that we investigate,
+/// any closure `FnMut(T) -> Result<T, E>` can be used
+pub type FnWithRoots<'a, E, T = f64> = dyn FnMut(T) -> Result<T, E> + 'a;
 
 /// Solver function that performs one root finding iteration
-pub type Solver<S> = fn(
-    FnWithRoots,
-    Range,
+pub type Solver<S, E, T = f64> = fn(
+    &mut FnWithRoots<'_, E, T>,
+    Range<T>,
     S,
-) -> Result<(f64, Range, S), RootsErr>;
+) -> Result<(T, Range<T>, S), RootsErr<E>>;
+
+/// Prepare for iterations, return initial root and x and y ranges
+pub type Init<E, T = f64> = fn(
+    &mut FnWithRoots<'_, E, T>,
+    T,
+    T,
+) -> Result<(T, Range<T>), RootsErr<E>>;
+
+/// Convert `f64` constant to the scalar type
+#[inline] pub(crate) fn cast<T: Float>(x: f64) -> T {
+    T::from(x).expect("constant is not representable")
+}
 
 
 /// Prepare for bisecting iterations.
 ///
-/// Check input range.
+/// Check input range, reversed range is put in order.
 /// Return x and y ranges and initial root.
 ///
-pub fn basic_init(
-    f: FnWithRoots,
-    x_left: f64,
-    x_right: f64) -> Result<(f64, Range), RootsErr>
+pub fn basic_init<E, T: Float>(
+    f: &mut FnWithRoots<'_, E, T>,
+    x_left: T,
+    x_right: T) -> Result<(T, Range<T>), RootsErr<E>>
 {
+    let (x_left, x_right) = if x_right < x_left { (x_right, x_left) } else { (x_left, x_right) };
+
     // guess root is in a middle of the range
-    let root = (x_left + x_right)/2.0;
+    let root = (x_left + x_right)/cast(2.0);
 
     let f_left = match f(x_left) {
         Ok(y) => y,
-        Err(_) => return Err(RootsErr::FunctionFailed),
+        Err(err) => return Err(RootsErr::FunctionFailed(err)),
     };
 
     let f_right = match f(x_right) {
         Ok(y) => y,
-        Err(_) => return Err(RootsErr::FunctionFailed),
+        Err(err) => return Err(RootsErr::FunctionFailed(err)),
     };
 
-    if (f_left < 0.0 && f_right < 0.0) || (f_left > 0.0 && f_right > 0.0) {
+    if f_left.is_nan() || f_right.is_nan() {
+        return Err(RootsErr::NotFinite);
+    }
+
+    if is_same_sign(f_left, f_right) {
         return Err(RootsErr::EndpointsNotStraddleYeq0);
     }
 
     Ok((root, (x_left, x_right, f_left, f_right)))
 }
 
+/// Prepare for open iterations that do not keep a bracket.
+///
+/// Evaluate function at both starting points, the range is not checked.
+/// Return x and y ranges and initial root.
+pub fn open_init<E, T: Float>(
+    f: &mut FnWithRoots<'_, E, T>,
+    x_left: T,
+    x_right: T) -> Result<(T, Range<T>), RootsErr<E>>
+{
+    let f_left = f(x_left).map_err(RootsErr::FunctionFailed)?;
+    let f_right = f(x_right).map_err(RootsErr::FunctionFailed)?;
+
+    Ok(((x_left + x_right)/cast(2.0), (x_left, x_right, f_left, f_right)))
+}
+
 /// Root finder perform root finding iterations
-pub struct RootFinder<S> {
+pub struct RootFinder<S, E = (), T = f64> {
     /// State that gets transferred  between iterations
     pub state: S,
+    /// Check the starting points, [`basic_init`] for bracketing solvers
+    init: Init<E, T>,
     ///
-    solver: Solver<S>
+    solver: Solver<S, E, T>
 }
 
 /// Root found value, precision, number iterations and etc.
 ///
-pub struct RootFinderResult {
+pub struct RootFinderResult<T = f64> {
     /// Root found value
-    pub root: f64,
+    pub root: T,
     /// Number of iterations
-    pub nr_iterations: usize
+    pub nr_iterations: usize,
+    /// Criterion that stopped iterations, `None` if `max_iterations` was exhausted
+    pub converged_by: Option<Convergence<T>>,
+    /// Final range, for bracketing solvers it brackets the root
+    pub range: Range<T>,
+    /// Function value at the root
+    pub residual: T,
+    /// Iterations stopped because `max_iterations` was reached
+    pub max_iterations_reached: bool,
 }
 
 /// State trait
-pub trait RootFinderState {
+///
+/// Finder `state` is cloned to start each search, so solver parameters
+/// kept in the state apply to every search made with the finder.
+pub trait RootFinderState: Clone {
     /// Create new state
     fn new() -> Self;
+
+    /// Criterion that [`RootFinder::find`] uses for `epsilon`,
+    /// step between two last root estimates by default
+    fn find_criterion<T: Float>(epsilon: T) -> Convergence<T> {
+        Convergence::Delta{epsabs: epsilon, epsrel: T::zero()}
+    }
 }
 
-impl<S: RootFinderState> RootFinder<S> {
+impl<S: RootFinderState, E, T: Float> RootFinder<S, E, T> {
+
+    /// Call solvers till `abs(prev-next) < epsilon`
+    ///
+    /// Same as [`RootFinder::find_with`] with [`RootFinderState::find_criterion`],
+    /// that is `Convergence::Delta{epsabs: epsilon, epsrel: 0.0}` for all solvers but Brent.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustamath::roots::bisection::new_bisection_finder;
+    /// # use assert_float_eq::*;
+    /// let temperature = 300.0;
+    /// let finder = new_bisection_finder();
+    /// let res = finder.find(|x: f64| Ok::<f64, ()>(x * x - temperature), 0.0, 100.0, 1.0e-12, 100);
+    /// assert_float_absolute_eq!(res.unwrap().root, temperature.sqrt(), 1.0e-12);
+    /// ```
+    pub fn find<F>(
+        &self,
+        fun: F,
+        x_left: T,
+        x_right: T,
+        epsilon: T,
+        max_iterations: usize
+    ) -> Result<RootFinderResult<T>, RootsErr<E>>
+    where
+        F: FnMut(T) -> Result<T, E>
+    {
+        self.find_with(fun, x_left, x_right,
+            &[S::find_criterion(epsilon)], max_iterations)
+    }
 
-    // TODO check https://github.com/ampl/gsl/blob/master/roots/convergence.c
-    /// Call solvers till `abs(prev-next) > epsilon`
-    pub fn find(
+    /// Call solvers till any of convergence criteria is met
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustamath::roots::{Convergence, brent::new_brent_finder};
+    /// let finder = new_brent_finder();
+    /// let criteria = [
+    ///     Convergence::Interval{epsabs: 0.0, epsrel: 1.0e-10},
+    ///     Convergence::Residual{epsabs: 1.0e-12},
+    /// ];
+    /// let res = finder.find_with(|x: f64| Ok::<f64, ()>(x.cos() - x), 0.0, 1.0, &criteria, 100).unwrap();
+    /// assert!(!res.max_iterations_reached);
+    /// assert!(res.residual.abs() < 1.0e-10);
+    /// assert!(res.range.0 <= res.root && res.root <= res.range.1);
+    /// ```
+    pub fn find_with<F>(
         &self,
-        fun: FnWithRoots,
-        x_left: f64,
-        x_right: f64,
-        epsilon: f64,
+        fun: F,
+        x_left: T,
+        x_right: T,
+        criteria: &[Convergence<T>],
         max_iterations: usize
-    ) -> Result<RootFinderResult, RootsErr>
+    ) -> Result<RootFinderResult<T>, RootsErr<E>>
+    where
+        F: FnMut(T) -> Result<T, E>
     {
-        let (mut root, mut range) = match basic_init(fun, x_left, x_right) {
-            Ok(res) => res,
-            Err(err) => { return Err(err); },
-        };
-
-        let mut state: S = S::new();
-
-        let mut nr_iterations: usize = 0;
-        let mut old_root = root;
-
-        while nr_iterations < max_iterations {
-            (root, range, state) = match (self.solver)(fun, range, state) {
-                Ok(res) => res,
-                Err(err) => { return Err(err); },
-            };
-            if expect_float_absolute_eq!(root, old_root, epsilon).is_ok() {
-                break;
-            }
-            old_root = root;
-            nr_iterations += 1;
-        }
-
-        // cargo test --lib xxx -- --nocapture
-        //println!("root={} iterations={}", root, i);
-
-        Ok(RootFinderResult {
-            root,
-            nr_iterations
-        })
+        let mut stepper = self.stepper(fun, x_left, x_right)?;
+        stepper::run(&mut stepper, criteria, max_iterations, None)
     }
+}
 
+/// Return true if both values are not zero and have the same sign
+#[inline] pub(crate) fn is_same_sign<T: Float>(a: T, b: T) -> bool {
+    (a < T::zero() && b < T::zero()) || (a > T::zero() && b > T::zero())
 }
@@ -3,9 +3,11 @@
 //! (c) Igor Lesik 2023
 //! MIT license
 
-use super::{RootsErr, Range, FnWithRoots, RootFinder, RootFinderState};
+use num_traits::float::Float;
+use super::{RootsErr, Range, FnWithRoots, RootFinder, RootFinderState, cast, basic_init};
 
 /// Bisection state that is transferred between iteration
+#[derive(Clone)]
 pub struct BisectionState {}
 
 impl RootFinderState for BisectionState {
@@ -13,46 +15,50 @@ impl RootFinderState for BisectionState {
 }
 
 /// Bisect searching range by half
-pub fn bisection_iterate(
-    f: FnWithRoots,
-    (x_left, x_right, f_left, f_right): Range,
+pub fn bisection_iterate<E, T: Float>(
+    f: &mut FnWithRoots<'_, E, T>,
+    (x_left, x_right, f_left, f_right): Range<T>,
     state: BisectionState,
-    ) -> Result<(f64, Range, BisectionState), RootsErr>
+    ) -> Result<(T, Range<T>, BisectionState), RootsErr<E>>
 {
-    if f_left == 0.0 {
+    let zero = T::zero();
+    let two: T = cast(2.0);
+
+    if f_left == zero {
         return Ok((/*root=*/x_left, (x_left, x_left, f_left, f_left), state));
     }
 
-    if f_right == 0.0 {
+    if f_right == zero {
         return Ok((/*root=*/x_right, (x_right, x_right, f_right, f_right), state));
     }
 
-    let x_bisect = (x_left + x_right) / 2.0;
+    let x_bisect = (x_left + x_right) / two;
 
     let f_bisect = match f(x_bisect) {
         Ok(y) => y,
-        Err(_) => return Err(RootsErr::FunctionFailed),
+        Err(err) => return Err(RootsErr::FunctionFailed(err)),
     };
 
-    if f_bisect == 0.0 {
+    if f_bisect == zero {
         return Ok((/*root=*/x_bisect, (x_bisect, x_bisect, f_bisect, f_bisect), state));
     }
 
     // Discard the half of the interval which doesn't contain the root.
-    if (f_left > 0.0 && f_bisect < 0.0) || (f_left < 0.0 && f_bisect > 0.0) {
-        let root = (x_left + x_bisect) / 2.0;
+    if (f_left > zero && f_bisect < zero) || (f_left < zero && f_bisect > zero) {
+        let root = (x_left + x_bisect) / two;
         Ok((root, (x_left, x_bisect, f_left, f_bisect), state))
     }
     else {
-        let root = (x_bisect + x_right) / 2.0;
+        let root = (x_bisect + x_right) / two;
         Ok((root, (x_bisect, x_right, f_bisect, f_right), state))
     }
 }
 
 /// Create new bisection root finder
-pub fn new_bisection_finder() -> RootFinder<BisectionState> {
-    RootFinder::<BisectionState> {
+pub fn new_bisection_finder<E, T: Float>() -> RootFinder<BisectionState, E, T> {
+    RootFinder::<BisectionState, E, T> {
         state: BisectionState {},
+        init: basic_init,
         solver: bisection_iterate,
     }
 }
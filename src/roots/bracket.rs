@@ -0,0 +1,221 @@
+//! Bracket search and scanning for all roots in a range.
+//!
+//! (c) Igor Lesik 2023
+//! MIT license
+//!
+//! References:
+//!
+//! - William H. Press - Numerical recipes, `zbrac` and `zbrak`
+
+use num_traits::float::Float;
+use super::{RootsErr, Range, RootFinder, RootFinderState, Convergence, cast, is_same_sign};
+
+/// Range growth factor for bracket expansion
+const EXPAND_FACTOR: f64 = 1.6;
+
+/// Expand range `[x_guess - step, x_guess + step]` outward till it brackets a root
+///
+/// On each iteration the endpoint with smaller `|f(x)|` is moved away
+/// by `1.6` times the range width. Range that grows over an even number
+/// of roots has no sign change and expansion continues past them.
+///
+/// # Example
+///
+/// ```
+/// # use rustamath::roots::bracket::expand_bracket;
+/// let (x_left, x_right, f_left, f_right) =
+///     expand_bracket(|x: f64| Ok::<f64, ()>(x*x*x - 2.0), 10.0, 1.0, 50).unwrap();
+/// assert!(x_left < 2.0f64.cbrt() && 2.0f64.cbrt() < x_right);
+/// assert!(f_left * f_right <= 0.0);
+/// ```
+pub fn expand_bracket<E, T, F>(
+    mut f: F,
+    x_guess: T,
+    step: T,
+    max_iterations: usize,
+) -> Result<Range<T>, RootsErr<E>>
+where
+    T: Float,
+    F: FnMut(T) -> Result<T, E>
+{
+    let factor: T = cast(EXPAND_FACTOR);
+
+    let (mut x_left, mut x_right) = (x_guess - step.abs(), x_guess + step.abs());
+
+    if x_left == x_right {
+        return Err(RootsErr::BracketNotFound);
+    }
+
+    let mut f_left = f(x_left).map_err(RootsErr::FunctionFailed)?;
+    let mut f_right = f(x_right).map_err(RootsErr::FunctionFailed)?;
+
+    for _ in 0..max_iterations {
+        if !is_same_sign(f_left, f_right) {
+            return Ok((x_left, x_right, f_left, f_right));
+        }
+        if f_left.abs() < f_right.abs() {
+            x_left = x_left + factor * (x_left - x_right);
+            f_left = f(x_left).map_err(RootsErr::FunctionFailed)?;
+        }
+        else {
+            x_right = x_right + factor * (x_right - x_left);
+            f_right = f(x_right).map_err(RootsErr::FunctionFailed)?;
+        }
+    }
+
+    if !is_same_sign(f_left, f_right) {
+        return Ok((x_left, x_right, f_left, f_right));
+    }
+
+    Err(RootsErr::BracketNotFound)
+}
+
+/// Split `[x_left, x_right]` into `nr_subintervals` equal parts and
+/// return parts where the function changes sign
+///
+/// Sample points where `f(x) == 0` are returned as degenerate ranges `(x, x, 0, 0)`.
+/// Roots of even multiplicity and pairs of roots closer than the subinterval width
+/// do not change sign and are not found.
+pub fn scan_brackets<E, T, F>(
+    mut f: F,
+    x_left: T,
+    x_right: T,
+    nr_subintervals: usize,
+) -> Result<Vec<Range<T>>, RootsErr<E>>
+where
+    T: Float,
+    F: FnMut(T) -> Result<T, E>
+{
+    let nr_subintervals = nr_subintervals.max(1);
+    let dx = (x_right - x_left) / cast(nr_subintervals as f64);
+
+    let mut brackets = Vec::new();
+
+    let mut x0 = x_left;
+    let mut f0 = f(x0).map_err(RootsErr::FunctionFailed)?;
+
+    if f0 == T::zero() {
+        brackets.push((x0, x0, f0, f0));
+    }
+
+    for i in 1..=nr_subintervals {
+        let x1 = if i == nr_subintervals { x_right } else { x_left + dx * cast(i as f64) };
+        let f1 = f(x1).map_err(RootsErr::FunctionFailed)?;
+
+        if f1 == T::zero() {
+            brackets.push((x1, x1, f1, f1));
+        }
+        else if f0 != T::zero() && !is_same_sign(f0, f1) {
+            brackets.push((x0, x1, f0, f1));
+        }
+
+        x0 = x1;
+        f0 = f1;
+    }
+
+    Ok(brackets)
+}
+
+/// Root found by scanning the range
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ScannedRoot<T = f64> {
+    /// Root value
+    pub root: T,
+    /// Estimated multiplicity, always odd since the root changes sign
+    pub multiplicity: usize,
+    /// Function value at the root
+    pub residual: T,
+    /// Subinterval where the root was found
+    pub bracket: Range<T>,
+}
+
+/// Estimate root multiplicity `m` assuming `f(x) ≈ c*(x - root)^m` near the root
+///
+/// Compares `|f|` at distances `h` and `2h` on both sides of the root,
+/// the estimate is rounded to nearest odd number.
+pub fn estimate_odd_multiplicity<E, T, F>(mut f: F, root: T, h: T) -> Result<usize, RootsErr<E>>
+where
+    T: Float,
+    F: FnMut(T) -> Result<T, E>
+{
+    let mut order = |h: T| -> Result<Option<f64>, RootsErr<E>> {
+        let f1 = f(root + h).map_err(RootsErr::FunctionFailed)?.abs();
+        let f2 = f(root + cast::<T>(2.0)*h).map_err(RootsErr::FunctionFailed)?.abs();
+        if f1 == T::zero() || f2 == T::zero() || !f1.is_finite() || !f2.is_finite() {
+            return Ok(None);
+        }
+        Ok((f2 / f1).log2().to_f64())
+    };
+
+    let m = match (order(h)?, order(-h)?) {
+        (Some(m1), Some(m2)) => (m1 + m2) / 2.0,
+        (Some(m), None) | (None, Some(m)) => m,
+        (None, None) => 1.0,
+    };
+
+    // nearest odd number that is at least 1
+    let odd = 2.0 * ((m - 1.0) / 2.0).round() + 1.0;
+    Ok(if odd < 1.0 { 1 } else { odd as usize })
+}
+
+impl<S: RootFinderState, E, T: Float> RootFinder<S, E, T> {
+
+    /// Find all roots in `[x_left, x_right]` where the function changes sign
+    ///
+    /// The range is split into `nr_subintervals` parts, each part with sign change
+    /// is refined with this finder. Sign changes at poles, where `|f(root)|` is larger
+    /// than `|f|` at the subinterval endpoints, are dropped.
+    /// Roots are sorted in ascending order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustamath::roots::{Convergence, brent::new_brent_finder};
+    /// # use assert_float_eq::*;
+    /// let finder = new_brent_finder();
+    /// let roots = finder.find_all(|x: f64| Ok::<f64, ()>(x.sin()), -10.0, 10.0, 100,
+    ///     &[Convergence::Interval{epsabs: 1.0e-14, epsrel: 0.0}], 100).unwrap();
+    /// assert_eq!(roots.len(), 7);
+    /// assert_float_absolute_eq!(roots[0].root, -3.0 * std::f64::consts::PI, 1.0e-13);
+    /// ```
+    pub fn find_all<F>(
+        &self,
+        mut fun: F,
+        x_left: T,
+        x_right: T,
+        nr_subintervals: usize,
+        criteria: &[Convergence<T>],
+        max_iterations: usize
+    ) -> Result<Vec<ScannedRoot<T>>, RootsErr<E>>
+    where
+        F: FnMut(T) -> Result<T, E>
+    {
+        let brackets = scan_brackets(&mut fun, x_left, x_right, nr_subintervals)?;
+
+        let h = cast::<T>(1.0e-3) * (x_right - x_left).abs() / cast(nr_subintervals.max(1) as f64);
+
+        let mut roots = Vec::with_capacity(brackets.len());
+
+        for bracket in brackets {
+            let (root, residual) = if bracket.0 == bracket.1 {
+                (bracket.0, T::zero())
+            }
+            else {
+                let res = self.find_with(&mut fun, bracket.0, bracket.1, criteria, max_iterations)?;
+                (res.root, res.residual)
+            };
+
+            if residual.abs() > bracket.2.abs().max(bracket.3.abs()) {
+                continue;
+            }
+
+            let multiplicity = estimate_odd_multiplicity(&mut fun, root, h)?;
+
+            roots.push(ScannedRoot {root, multiplicity, residual, bracket});
+        }
+
+        roots.sort_by(|a, b| a.root.partial_cmp(&b.root).unwrap_or(std::cmp::Ordering::Equal));
+
+        Ok(roots)
+    }
+}
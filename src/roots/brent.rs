@@ -0,0 +1,204 @@
+//! Brent-Dekker root finding algorithm.
+//!
+//! (c) Igor Lesik 2023
+//! MIT license
+//!
+//! References:
+//!
+//! - <https://en.wikipedia.org/wiki/Brent%27s_method>
+//! - <https://github.com/ampl/gsl/blob/master/roots/brent.c>
+
+use num_traits::float::Float;
+use super::{RootsErr, Range, FnWithRoots, RootFinder, RootFinderState, Convergence, cast,
+    is_same_sign, basic_init};
+
+/// Brent state that is transferred between iteration
+///
+/// `b` is the best root estimate, `a` is the previous estimate,
+/// `c` is the contrapoint such that `[b, c]` brackets the root,
+/// `d` is the last step and `e` is the step before last.
+#[derive(Clone)]
+pub struct BrentState<T = f64> {
+    a: T,
+    b: T,
+    c: T,
+    d: T,
+    e: T,
+    fa: T,
+    fb: T,
+    fc: T,
+    started: bool,
+}
+
+impl<T: Float> RootFinderState for BrentState<T> {
+    fn new() -> BrentState<T> {
+        BrentState {
+            a: T::nan(), b: T::nan(), c: T::nan(), d: T::nan(), e: T::nan(),
+            fa: T::nan(), fb: T::nan(), fc: T::nan(),
+            started: false,
+        }
+    }
+
+    /// Brent-Dekker stops on the bracket width, small interpolation steps
+    /// far from the root happen on flat functions
+    fn find_criterion<U: Float>(epsilon: U) -> Convergence<U> {
+        Convergence::Interval{epsabs: epsilon, epsrel: U::zero()}
+    }
+}
+
+impl<T: Float> BrentState<T> {
+    /// Initialize state with the bracketing range
+    fn start(&mut self, (x_left, x_right, f_left, f_right): Range<T>) {
+        self.a = x_left;
+        self.fa = f_left;
+        self.b = x_right;
+        self.fb = f_right;
+        self.c = x_right;
+        self.fc = f_right;
+        self.d = x_right - x_left;
+        self.e = x_right - x_left;
+        self.started = true;
+    }
+
+    /// Return range `[b, c]` ordered left to right
+    fn range(b: T, c: T, fb: T, fc: T) -> Range<T> {
+        if b < c { (b, c, fb, fc) } else { (c, b, fc, fb) }
+    }
+}
+
+/// Brent iteration: inverse quadratic interpolation, secant or bisection
+///
+/// The input range is used only on the first iteration to initialize the state,
+/// after that the state keeps the bracket.
+pub fn brent_iterate<E, T: Float>(
+    f: &mut FnWithRoots<'_, E, T>,
+    range: Range<T>,
+    mut state: BrentState<T>,
+    ) -> Result<(T, Range<T>, BrentState<T>), RootsErr<E>>
+{
+    if !state.started {
+        state.start(range);
+    }
+
+    let BrentState {mut a, mut b, mut c, mut d, mut e, mut fa, mut fb, mut fc, ..} = state;
+
+    let mut ac_equal = false;
+
+    let zero = T::zero();
+    let one = T::one();
+    let two: T = cast(2.0);
+    let half: T = cast(0.5);
+
+    if is_same_sign(fb, fc) {
+        ac_equal = true;
+        c = a;
+        fc = fa;
+        d = b - a;
+        e = b - a;
+    }
+
+    // Make `b` the best estimate.
+    if fc.abs() < fb.abs() {
+        ac_equal = true;
+        a = b;
+        b = c;
+        c = a;
+        fa = fb;
+        fb = fc;
+        fc = fa;
+    }
+
+    let tol = half * T::epsilon() * b.abs();
+    let m = half * (c - b);
+
+    if fb == T::zero() {
+        return Ok((b, (b, b, fb, fb), BrentState{a, b, c, d, e, fa, fb, fc, started: true}));
+    }
+
+    if m.abs() <= tol {
+        return Ok((b, BrentState::range(b, c, fb, fc),
+            BrentState{a, b, c, d, e, fa, fb, fc, started: true}));
+    }
+
+    if e.abs() < tol || fa.abs() <= fb.abs() {
+        // use bisection
+        d = m;
+        e = m;
+    }
+    else {
+        // use secant or inverse quadratic interpolation
+        let s = fb / fa;
+        let (mut p, mut q) = if ac_equal {
+            (two * m * s, one - s)
+        }
+        else {
+            let q = fa / fc;
+            let r = fb / fc;
+            (s * (two * m * q * (q - r) - (b - a) * (r - one)),
+             (q - one) * (r - one) * (s - one))
+        };
+
+        if p > zero { q = -q; } else { p = -p; }
+
+        if two * p < (cast::<T>(3.0) * m * q - (tol * q).abs()).min((e * q).abs()) {
+            e = d;
+            d = p / q;
+        }
+        else {
+            // interpolation failed, fall back to bisection
+            d = m;
+            e = m;
+        }
+    }
+
+    a = b;
+    fa = fb;
+
+    if d.abs() > tol {
+        b = b + d;
+    }
+    else {
+        b = b + if m > zero { tol } else { -tol };
+    }
+
+    fb = match f(b) {
+        Ok(y) => y,
+        Err(err) => return Err(RootsErr::FunctionFailed(err)),
+    };
+
+    // Bracket for the caller is `[b, c]` or `[b, a]`, the state keeps `c`
+    // and fixes it on the next iteration.
+    let range = if is_same_sign(fb, fc) {
+        BrentState::range(b, a, fb, fa)
+    }
+    else {
+        BrentState::range(b, c, fb, fc)
+    };
+
+    Ok((b, range, BrentState{a, b, c, d, e, fa, fb, fc, started: true}))
+}
+
+/// Create new Brent root finder
+pub fn new_brent_finder<E, T: Float>() -> RootFinder<BrentState<T>, E, T> {
+    RootFinder::<BrentState<T>, E, T> {
+        state: BrentState::new(),
+        init: basic_init,
+        solver: brent_iterate,
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_brent() {
+    #[inline] fn sin(x: f64) -> Result<f64, ()> {
+        Ok(x.sin())
+    }
+
+    let finder = new_brent_finder();
+
+    let res = finder.find(sin, 3.0, 4.0, 1.0e-15, 50)
+        .expect("root finder failed");
+
+    assert!(res.nr_iterations < 10);
+    assert_f64_near!(res.root, std::f64::consts::PI, 4);
+}
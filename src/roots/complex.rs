@@ -0,0 +1,211 @@
+//! Root finding in the complex plane.
+//!
+//! (c) Igor Lesik 2023
+//! MIT license
+//!
+//! Roots of analytic functions `f(z)` are found from an initial guess,
+//! unlike real root finders no bracketing is possible.
+//!
+//! References:
+//!
+//! - <https://en.wikipedia.org/wiki/Muller%27s_method>
+//! - <https://en.wikipedia.org/wiki/Newton%27s_method#Complex_functions>
+
+use num_complex::Complex;
+use crate::function::complex::abs;
+use super::{RootsErr, RootFinderState, Convergence};
+
+pub mod muller;
+pub mod newton;
+
+/// Complex function with roots, any closure
+/// `FnMut(Complex<f64>) -> Result<Complex<f64>, E>` can be used
+pub type FnWithComplexRoots<'a, E> = dyn FnMut(Complex<f64>) -> Result<Complex<f64>, E> + 'a;
+
+/// Complex function that returns `(f(z), df(z)/dz)` in one call
+pub type FnWithComplexDerivative<'a, E> =
+    dyn FnMut(Complex<f64>) -> Result<(Complex<f64>, Complex<f64>), E> + 'a;
+
+/// Solver function that performs one iteration from current root estimate,
+/// returns next root estimate and function value at it
+pub type ComplexSolver<S, E> = fn(
+    &mut FnWithComplexRoots<'_, E>,
+    Complex<f64>,
+    S,
+) -> Result<(Complex<f64>, Complex<f64>, S), RootsErr<E>>;
+
+/// Solver function that uses derivative and performs one iteration
+/// from current root estimate, returns next root estimate and function value at it
+pub type ComplexDerivSolver<S, E> = fn(
+    &mut FnWithComplexDerivative<'_, E>,
+    Complex<f64>,
+    S,
+) -> Result<(Complex<f64>, Complex<f64>, S), RootsErr<E>>;
+
+/// Complex root finder that does not need derivative
+pub struct ComplexRootFinder<S, E = ()> {
+    /// State that gets transferred between iterations
+    pub state: S,
+    solver: ComplexSolver<S, E>,
+}
+
+/// Complex root finder that uses derivative
+pub struct ComplexDerivRootFinder<S, E = ()> {
+    /// State that gets transferred between iterations
+    pub state: S,
+    solver: ComplexDerivSolver<S, E>,
+}
+
+/// Complex root found value, residual, number iterations and etc.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ComplexRootFinderResult {
+    /// Root found value
+    pub root: Complex<f64>,
+    /// Number of iterations
+    pub nr_iterations: usize,
+    /// Criterion that stopped iterations, `None` if `max_iterations` was exhausted
+    pub converged_by: Option<Convergence>,
+    /// Function value at the root
+    pub residual: Complex<f64>,
+    /// Iterations stopped because `max_iterations` was reached
+    pub max_iterations_reached: bool,
+}
+
+/// Combine complex function `f(z)` and its derivative `df(z)/dz` into one function
+pub fn complex_fn_and_derivative<E, F, DF>(
+    mut f: F,
+    mut df: DF
+) -> impl FnMut(Complex<f64>) -> Result<(Complex<f64>, Complex<f64>), E>
+where
+    F: FnMut(Complex<f64>) -> Result<Complex<f64>, E>,
+    DF: FnMut(Complex<f64>) -> Result<Complex<f64>, E>,
+{
+    move |z| Ok((f(z)?, df(z)?))
+}
+
+/// Check convergence criterion for complex root estimates
+///
+/// `Delta` tests `|z1 - z0| < epsabs + epsrel * |z1|`, `Residual` tests `|f(z1)| < epsabs`.
+/// There is no range in the complex plane, `Interval` is checked like `Delta`.
+pub fn complex_converged(
+    criterion: &Convergence,
+    root: Complex<f64>,
+    old_root: Complex<f64>,
+    residual: Complex<f64>) -> bool
+{
+    match *criterion {
+        Convergence::Interval {epsabs, epsrel} | Convergence::Delta {epsabs, epsrel} =>
+            root == old_root || abs(root - old_root) < epsabs + epsrel * abs(root),
+        Convergence::Residual {epsabs} =>
+            residual == Complex::new(0.0, 0.0) || abs(residual) < epsabs,
+    }
+}
+
+/// Iterate till any of convergence criteria is met or `max_iterations` is exhausted
+fn run<S, E, I>(
+    mut iterate: I,
+    mut state: S,
+    guess: Complex<f64>,
+    criteria: &[Convergence],
+    max_iterations: usize,
+) -> Result<ComplexRootFinderResult, RootsErr<E>>
+where
+    S: RootFinderState,
+    I: FnMut(Complex<f64>, S) -> Result<(Complex<f64>, Complex<f64>, S), RootsErr<E>>
+{
+    if !criteria.iter().all(Convergence::is_valid) {
+        return Err(RootsErr::BadTolerance);
+    }
+
+    let mut root = guess;
+    let mut old_root = Complex::new(f64::NAN, f64::NAN);
+    let mut residual = Complex::new(f64::NAN, f64::NAN);
+    let mut nr_iterations: usize = 0;
+    let mut converged_by = None;
+
+    while nr_iterations < max_iterations {
+        (root, residual, state) = iterate(root, state)?;
+        converged_by = criteria.iter()
+            .find(|c| complex_converged(c, root, old_root, residual))
+            .copied();
+        if converged_by.is_some() {
+            break;
+        }
+        old_root = root;
+        nr_iterations += 1;
+    }
+
+    Ok(ComplexRootFinderResult {
+        root,
+        nr_iterations,
+        converged_by,
+        residual,
+        max_iterations_reached: converged_by.is_none(),
+    })
+}
+
+impl<S: RootFinderState, E> ComplexRootFinder<S, E> {
+
+    /// Call solvers till `|prev-next| < epsilon`
+    pub fn find<F>(
+        &self,
+        fun: F,
+        guess: Complex<f64>,
+        epsilon: f64,
+        max_iterations: usize
+    ) -> Result<ComplexRootFinderResult, RootsErr<E>>
+    where
+        F: FnMut(Complex<f64>) -> Result<Complex<f64>, E>
+    {
+        self.find_with(fun, guess, &[Convergence::Delta{epsabs: epsilon, epsrel: 0.0}],
+            max_iterations)
+    }
+
+    /// Call solvers till any of convergence criteria is met
+    pub fn find_with<F>(
+        &self,
+        mut fun: F,
+        guess: Complex<f64>,
+        criteria: &[Convergence],
+        max_iterations: usize
+    ) -> Result<ComplexRootFinderResult, RootsErr<E>>
+    where
+        F: FnMut(Complex<f64>) -> Result<Complex<f64>, E>
+    {
+        run(|root, state| (self.solver)(&mut fun, root, state), self.state.clone(),
+            guess, criteria, max_iterations)
+    }
+}
+
+impl<S: RootFinderState, E> ComplexDerivRootFinder<S, E> {
+
+    /// Call solvers till `|prev-next| < epsilon`
+    pub fn find<F>(
+        &self,
+        fun: F,
+        guess: Complex<f64>,
+        epsilon: f64,
+        max_iterations: usize
+    ) -> Result<ComplexRootFinderResult, RootsErr<E>>
+    where
+        F: FnMut(Complex<f64>) -> Result<(Complex<f64>, Complex<f64>), E>
+    {
+        self.find_with(fun, guess, &[Convergence::Delta{epsabs: epsilon, epsrel: 0.0}],
+            max_iterations)
+    }
+
+    /// Call solvers till any of convergence criteria is met
+    pub fn find_with<F>(
+        &self,
+        mut fun: F,
+        guess: Complex<f64>,
+        criteria: &[Convergence],
+        max_iterations: usize
+    ) -> Result<ComplexRootFinderResult, RootsErr<E>>
+    where
+        F: FnMut(Complex<f64>) -> Result<(Complex<f64>, Complex<f64>), E>
+    {
+        run(|root, state| (self.solver)(&mut fun, root, state), self.state.clone(),
+            guess, criteria, max_iterations)
+    }
+}
@@ -0,0 +1,146 @@
+//! Müller root finding algorithm.
+//!
+//! (c) Igor Lesik 2023
+//! MIT license
+//!
+//! References:
+//!
+//! - <https://en.wikipedia.org/wiki/Muller%27s_method>
+//! - William H. Press - Numerical recipes, 9.5.2 Muller's Method
+
+use num_complex::Complex;
+use crate::function::complex::abs;
+use super::{RootsErr, RootFinderState, FnWithComplexRoots, ComplexRootFinder};
+
+/// Müller state keeps three last iterates, `x2` is the latest
+#[derive(Clone)]
+pub struct MullerState {
+    x0: Complex<f64>,
+    x1: Complex<f64>,
+    f0: Complex<f64>,
+    f1: Complex<f64>,
+    f2: Complex<f64>,
+    started: bool,
+}
+
+impl RootFinderState for MullerState {
+    fn new() -> MullerState {
+        let nan = Complex::new(f64::NAN, f64::NAN);
+        MullerState{x0: nan, x1: nan, f0: nan, f1: nan, f2: nan, started: false}
+    }
+}
+
+/// Müller step: next root estimate is a root of the parabola through three last points
+///
+/// The first iteration starts from points `guess - h`, `guess + h` and `guess`,
+/// where `h = 0.1 * max(|guess|, 1)`.
+/// Parabola may have complex roots, so iterations leave the real axis
+/// even when they start on it.
+///
+/// # Example
+///
+/// ```
+/// # use rustamath::roots::complex::muller::new_muller_finder;
+/// # use num_complex::Complex;
+/// # use assert_float_eq::*;
+/// let finder = new_muller_finder();
+/// let res = finder.find(|z: Complex<f64>| Ok::<_, ()>(z*z + 1.0), Complex::new(0.5, 0.0), 1.0e-15, 50);
+/// let root = res.unwrap().root;
+/// assert_float_absolute_eq!(root.re, 0.0, 1.0e-15);
+/// assert_float_absolute_eq!(root.im.abs(), 1.0, 1.0e-15);
+/// ```
+pub fn muller_iterate<E>(
+    f: &mut FnWithComplexRoots<'_, E>,
+    x2: Complex<f64>,
+    state: MullerState,
+    ) -> Result<(Complex<f64>, Complex<f64>, MullerState), RootsErr<E>>
+{
+    let MullerState {x0, x1, f0, f1, f2, ..} = if state.started {
+        state
+    }
+    else {
+        let h = 0.1 * abs(x2).max(1.0);
+        let (x0, x1) = (x2 - h, x2 + h);
+        MullerState {
+            x0, x1,
+            f0: f(x0).map_err(RootsErr::FunctionFailed)?,
+            f1: f(x1).map_err(RootsErr::FunctionFailed)?,
+            f2: f(x2).map_err(RootsErr::FunctionFailed)?,
+            started: true,
+        }
+    };
+
+    let zero = Complex::new(0.0, 0.0);
+
+    if f2 == zero || x2 == x1 || x1 == x0 {
+        return Ok((x2, f2, MullerState{x0, x1, f0, f1, f2, started: true}));
+    }
+
+    let h1 = x1 - x0;
+    let h2 = x2 - x1;
+    let d1 = (f1 - f0) / h1;
+    let d2 = (f2 - f1) / h2;
+    let a = (d2 - d1) / (h2 + h1);
+    let b = a * h2 + d2;
+    let disc = (b * b - 4.0 * a * f2).sqrt();
+
+    // choose sign that gives larger denominator and smaller step
+    let den = if abs(b + disc) >= abs(b - disc) { b + disc } else { b - disc };
+
+    if den == zero {
+        return Err(RootsErr::ZeroDerivative);
+    }
+
+    let x3 = x2 - 2.0 * f2 / den;
+
+    if !(x3.re.is_finite() && x3.im.is_finite()) {
+        return Err(RootsErr::ZeroDerivative);
+    }
+
+    let f3 = f(x3).map_err(RootsErr::FunctionFailed)?;
+
+    Ok((x3, f3, MullerState{x0: x1, x1: x2, f0: f1, f1: f2, f2: f3, started: true}))
+}
+
+/// Create new Müller root finder
+pub fn new_muller_finder<E>() -> ComplexRootFinder<MullerState, E> {
+    ComplexRootFinder::<MullerState, E> {
+        state: MullerState::new(),
+        solver: muller_iterate,
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_muller() {
+    use crate::roots::Convergence;
+
+    // poles of transfer function 1/(s^3 + 2s^2 + 2s + 1) = 1/((s+1)(s^2+s+1))
+    let denominator = |s: Complex<f64>| -> Result<Complex<f64>, ()> {
+        Ok(((s + 2.0) * s + 2.0) * s + 1.0)
+    };
+
+    let finder = new_muller_finder();
+    let criteria = [
+        Convergence::Delta{epsabs: 0.0, epsrel: 1.0e-15},
+        Convergence::Residual{epsabs: 1.0e-15},
+    ];
+
+    let res = finder.find_with(denominator, Complex::new(0.0, 1.0), &criteria, 50)
+        .expect("root finder failed");
+    assert!(res.nr_iterations < 20);
+    assert_float_absolute_eq!(res.root.re, -0.5, 1.0e-14);
+    assert_float_absolute_eq!(res.root.im, 3.0f64.sqrt() / 2.0, 1.0e-14);
+
+    let res = finder.find_with(denominator, Complex::new(-1.5, 0.0), &criteria, 50)
+        .expect("root finder failed");
+    assert_float_absolute_eq!(res.root.re, -1.0, 1.0e-14);
+    assert_float_absolute_eq!(res.root.im, 0.0, 1.0e-14);
+
+    // zeros of sin(z) with function::complex
+    let res = finder.find_with(|z| Ok::<_, ()>(crate::function::complex::sin(z)),
+        Complex::new(3.0, 0.5), &criteria, 50)
+        .expect("root finder failed");
+    assert_float_absolute_eq!(res.root.re, std::f64::consts::PI, 1.0e-14);
+    assert_float_absolute_eq!(res.root.im, 0.0, 1.0e-14);
+}
@@ -0,0 +1,107 @@
+//! Newton root finding algorithm for complex analytic functions.
+//!
+//! (c) Igor Lesik 2023
+//! MIT license
+//!
+//! References:
+//!
+//! - <https://en.wikipedia.org/wiki/Newton%27s_method#Complex_functions>
+
+use num_complex::Complex;
+use super::{RootsErr, RootFinderState, FnWithComplexDerivative, ComplexDerivRootFinder};
+
+/// Complex Newton state keeps function value and derivative at current root estimate
+#[derive(Clone)]
+pub struct ComplexNewtonState {
+    f: Complex<f64>,
+    df: Complex<f64>,
+    started: bool,
+}
+
+impl RootFinderState for ComplexNewtonState {
+    fn new() -> ComplexNewtonState {
+        let nan = Complex::new(f64::NAN, f64::NAN);
+        ComplexNewtonState{f: nan, df: nan, started: false}
+    }
+}
+
+/// Newton step `z_next = z - f(z)/f'(z)`
+///
+/// # Example
+///
+/// ```
+/// # use rustamath::roots::complex::{complex_fn_and_derivative, newton::new_complex_newton_finder};
+/// # use num_complex::Complex;
+/// # use assert_float_eq::*;
+/// // z^3 = 1
+/// let fdf = complex_fn_and_derivative(
+///     |z: Complex<f64>| Ok::<_, ()>(z*z*z - 1.0),
+///     |z: Complex<f64>| Ok(3.0*z*z));
+/// let res = new_complex_newton_finder().find(fdf, Complex::new(-1.0, 1.0), 1.0e-15, 50);
+/// let root = res.unwrap().root;
+/// assert_float_absolute_eq!(root.re, -0.5, 1.0e-15);
+/// assert_float_absolute_eq!(root.im, 3.0f64.sqrt() / 2.0, 1.0e-15);
+/// ```
+pub fn complex_newton_iterate<E>(
+    fdf: &mut FnWithComplexDerivative<'_, E>,
+    z: Complex<f64>,
+    state: ComplexNewtonState,
+    ) -> Result<(Complex<f64>, Complex<f64>, ComplexNewtonState), RootsErr<E>>
+{
+    let (f, df) = if state.started {
+        (state.f, state.df)
+    }
+    else {
+        fdf(z).map_err(RootsErr::FunctionFailed)?
+    };
+
+    if f == Complex::new(0.0, 0.0) {
+        return Ok((z, f, ComplexNewtonState{f, df, started: true}));
+    }
+
+    let z_next = z - f / df;
+
+    if !(z_next.re.is_finite() && z_next.im.is_finite()) {
+        return Err(RootsErr::ZeroDerivative);
+    }
+
+    let (f, df) = fdf(z_next).map_err(RootsErr::FunctionFailed)?;
+
+    Ok((z_next, f, ComplexNewtonState{f, df, started: true}))
+}
+
+/// Create new complex Newton root finder
+pub fn new_complex_newton_finder<E>() -> ComplexDerivRootFinder<ComplexNewtonState, E> {
+    ComplexDerivRootFinder::<ComplexNewtonState, E> {
+        state: ComplexNewtonState::new(),
+        solver: complex_newton_iterate,
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_complex_newton() {
+    use crate::roots::Convergence;
+
+    // zeros of s^2 + 2*zeta*w*s + w^2 with w = 2, zeta = 0.25
+    let fdf = |s: Complex<f64>| -> Result<(Complex<f64>, Complex<f64>), ()> {
+        Ok((s*s + s + 4.0, 2.0*s + 1.0))
+    };
+
+    let finder = new_complex_newton_finder();
+
+    let res = finder.find(fdf, Complex::new(-1.0, 1.0), 1.0e-15, 50)
+        .expect("root finder failed");
+    assert!(res.nr_iterations < 10);
+    assert_float_absolute_eq!(res.root.re, -0.5, 1.0e-15);
+    assert_float_absolute_eq!(res.root.im, 15.0f64.sqrt() / 2.0, 1.0e-15);
+
+    let res = finder.find_with(fdf, Complex::new(0.0, -1.0),
+        &[Convergence::Residual{epsabs: 1.0e-14}], 50)
+        .expect("root finder failed");
+    assert_float_absolute_eq!(res.root.im, -(15.0f64.sqrt()) / 2.0, 1.0e-14);
+
+    // zero derivative at the guess
+    let res = finder.find(fdf, Complex::new(-0.5, 0.0), 1.0e-15, 50);
+    assert!(matches!(res, Err(RootsErr::ZeroDerivative)));
+}
@@ -0,0 +1,121 @@
+//! Convergence criteria (stopping tests) for root finding iterations.
+//!
+//! (c) Igor Lesik 2023
+//! MIT license
+//!
+//! References:
+//!
+//! - <https://github.com/ampl/gsl/blob/master/roots/convergence.c>
+//! - <https://www.gnu.org/software/gsl/doc/html/roots.html#search-stopping-parameters>
+
+use num_traits::float::Float;
+use super::Range;
+
+/// Criterion that stops root finding iterations
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Convergence<T = f64> {
+    /// Range is small enough, see [`interval_converged`]
+    Interval {
+        /// Absolute tolerance
+        epsabs: T,
+        /// Relative tolerance
+        epsrel: T
+    },
+    /// Step between two last root estimates is small enough, see [`delta_converged`]
+    Delta {
+        /// Absolute tolerance
+        epsabs: T,
+        /// Relative tolerance
+        epsrel: T
+    },
+    /// Function value at the root is small enough, see [`residual_converged`]
+    Residual {
+        /// Absolute tolerance
+        epsabs: T
+    },
+}
+
+impl<T: Float> Convergence<T> {
+    /// Return true if tolerances are not negative
+    pub fn is_valid(&self) -> bool {
+        match *self {
+            Convergence::Interval {epsabs, epsrel} => epsabs >= T::zero() && epsrel >= T::zero(),
+            Convergence::Delta {epsabs, epsrel} => epsabs >= T::zero() && epsrel >= T::zero(),
+            Convergence::Residual {epsabs} => epsabs >= T::zero(),
+        }
+    }
+
+    /// Return true if the criterion needs `f(root)` value
+    pub fn needs_residual(&self) -> bool {
+        matches!(self, Convergence::Residual {..})
+    }
+
+    /// Check the criterion
+    pub fn is_met(&self, root: T, old_root: T, range: Range<T>, residual: T) -> bool {
+        match *self {
+            Convergence::Interval {epsabs, epsrel} =>
+                interval_converged(range.0, range.1, epsabs, epsrel),
+            Convergence::Delta {epsabs, epsrel} =>
+                delta_converged(root, old_root, epsabs, epsrel),
+            Convergence::Residual {epsabs} =>
+                residual_converged(residual, epsabs),
+        }
+    }
+}
+
+/// Return first criterion that is met
+pub fn first_met<T: Float>(
+    criteria: &[Convergence<T>],
+    root: T,
+    old_root: T,
+    range: Range<T>,
+    residual: T) -> Option<Convergence<T>>
+{
+    criteria.iter().find(|c| c.is_met(root, old_root, range, residual)).copied()
+}
+
+/// Test range `[x_left, x_right]` with combined absolute and relative tolerance
+///
+/// `|x_right - x_left| < epsabs + epsrel * min(|x_left|, |x_right|)`,
+/// when the range includes `x=0` `min(|x_left|, |x_right|)` is replaced by 0.
+/// Degenerate range `x_left == x_right` is always converged.
+///
+/// # Example
+///
+/// ```
+/// # use rustamath::roots::convergence::*;
+/// assert!(interval_converged(1000.0, 1000.001, 0.0, 1.0e-5));
+/// assert!(!interval_converged(-0.001, 0.001, 0.0, 1.0e-5));
+/// ```
+pub fn interval_converged<T: Float>(x_left: T, x_right: T, epsabs: T, epsrel: T) -> bool {
+    let min_abs = if super::is_same_sign(x_left, x_right) {
+        x_left.abs().min(x_right.abs())
+    }
+    else {
+        T::zero()
+    };
+
+    let tolerance = epsabs + epsrel * min_abs;
+
+    x_left == x_right || (x_right - x_left).abs() < tolerance
+}
+
+/// Test step between two root estimates `|x1 - x0| < epsabs + epsrel * |x1|`
+///
+/// # Example
+///
+/// ```
+/// # use rustamath::roots::convergence::*;
+/// assert!(delta_converged(1.0, 1.0 + 1.0e-9, 1.0e-8, 0.0));
+/// assert!(!delta_converged(1.0, f64::NAN, 1.0e-8, 0.0));
+/// ```
+pub fn delta_converged<T: Float>(x1: T, x0: T, epsabs: T, epsrel: T) -> bool {
+    let tolerance = epsabs + epsrel * x1.abs();
+
+    x1 == x0 || (x1 - x0).abs() < tolerance
+}
+
+/// Test residual value `|f(x)| < epsabs`, exact root `f(x) == 0` is always converged
+pub fn residual_converged<T: Float>(f: T, epsabs: T) -> bool {
+    f == T::zero() || f.abs() < epsabs
+}
@@ -0,0 +1,182 @@
+//! False position (regula falsi) root finding algorithm.
+//!
+//! (c) Igor Lesik 2023
+//! MIT license
+//!
+//! References:
+//!
+//! - <https://en.wikipedia.org/wiki/Regula_falsi>
+//! - <https://github.com/ampl/gsl/blob/master/roots/falsepos.c>
+
+use num_traits::float::Float;
+use super::{RootsErr, Range, FnWithRoots, RootFinder, RootFinderState, cast, basic_init};
+
+/// Endpoint of the range
+#[derive(Clone, Copy, PartialEq)]
+pub enum Side {
+    /// No endpoint was replaced yet
+    None,
+    /// Left endpoint
+    Left,
+    /// Right endpoint
+    Right,
+}
+
+/// False position state that is transferred between iteration
+#[derive(Clone)]
+pub struct FalsePositionState {
+    /// Endpoint replaced on the last iteration
+    pub replaced: Side,
+}
+
+impl RootFinderState for FalsePositionState {
+    fn new() -> FalsePositionState { FalsePositionState{replaced: Side::None} }
+}
+
+/// Modification of the false position method
+#[derive(Clone, Copy)]
+enum Modification {
+    None,
+    Illinois,
+    AndersonBjorck,
+}
+
+fn false_position_step<E, T: Float>(
+    f: &mut FnWithRoots<'_, E, T>,
+    (x_left, x_right, f_left, f_right): Range<T>,
+    state: FalsePositionState,
+    modification: Modification,
+    ) -> Result<(T, Range<T>, FalsePositionState), RootsErr<E>>
+{
+    if f_left == T::zero() {
+        return Ok((/*root=*/x_left, (x_left, x_left, f_left, f_left), state));
+    }
+
+    if f_right == T::zero() {
+        return Ok((/*root=*/x_right, (x_right, x_right, f_right, f_right), state));
+    }
+
+    // Interpolation
+    let x_new = (f_right * x_left - f_left * x_right) / (f_right - f_left);
+
+    let f_new = match f(x_new) {
+        Ok(y) => y,
+        Err(err) => return Err(RootsErr::FunctionFailed(err)),
+    };
+
+    if f_new == T::zero() {
+        return Ok((/*root=*/x_new, (x_new, x_new, f_new, f_new), state));
+    }
+
+    // Scale value at the endpoint that was retained twice in a row.
+    let scale = |f_replaced: T| -> T {
+        match modification {
+            Modification::None => T::one(),
+            Modification::Illinois => cast(0.5),
+            Modification::AndersonBjorck => {
+                // Small `m` means no progress, A-B scaling then overshoots
+                // to the other endpoint and cycles, use Illinois instead.
+                let m = T::one() - f_new / f_replaced;
+                if m > cast(0.1) { m } else { cast(0.5) }
+            },
+        }
+    };
+
+    if f_new.is_sign_positive() == f_right.is_sign_positive() {
+        let f_left = if state.replaced == Side::Right { f_left * scale(f_right) } else { f_left };
+        Ok((x_new, (x_left, x_new, f_left, f_new), FalsePositionState{replaced: Side::Right}))
+    }
+    else {
+        let f_right = if state.replaced == Side::Left { f_right * scale(f_left) } else { f_right };
+        Ok((x_new, (x_new, x_right, f_new, f_right), FalsePositionState{replaced: Side::Left}))
+    }
+}
+
+/// Replace one of the endpoints with the secant line intersection with `y=0`
+///
+/// Converges slowly when one endpoint gets stuck, as for convex functions.
+pub fn false_position_iterate<E, T: Float>(
+    f: &mut FnWithRoots<'_, E, T>,
+    range: Range<T>,
+    state: FalsePositionState,
+    ) -> Result<(T, Range<T>, FalsePositionState), RootsErr<E>>
+{
+    false_position_step(f, range, state, Modification::None)
+}
+
+/// False position with Illinois modification
+///
+/// When the same endpoint is retained twice in a row its function value is halved,
+/// the range function values are modified values, not `f(x)`.
+pub fn illinois_iterate<E, T: Float>(
+    f: &mut FnWithRoots<'_, E, T>,
+    range: Range<T>,
+    state: FalsePositionState,
+    ) -> Result<(T, Range<T>, FalsePositionState), RootsErr<E>>
+{
+    false_position_step(f, range, state, Modification::Illinois)
+}
+
+/// False position with Anderson-Björck modification
+///
+/// When the same endpoint is retained twice in a row its function value is
+/// scaled by `m = 1 - f(x_new)/f(x_replaced)` or by `0.5` if `m <= 0.1`,
+/// the range function values are modified values, not `f(x)`.
+pub fn anderson_bjorck_iterate<E, T: Float>(
+    f: &mut FnWithRoots<'_, E, T>,
+    range: Range<T>,
+    state: FalsePositionState,
+    ) -> Result<(T, Range<T>, FalsePositionState), RootsErr<E>>
+{
+    false_position_step(f, range, state, Modification::AndersonBjorck)
+}
+
+/// Create new false position root finder
+pub fn new_false_position_finder<E, T: Float>() -> RootFinder<FalsePositionState, E, T> {
+    RootFinder::<FalsePositionState, E, T> {
+        state: FalsePositionState::new(),
+        init: basic_init,
+        solver: false_position_iterate,
+    }
+}
+
+/// Create new Illinois false position root finder
+pub fn new_illinois_finder<E, T: Float>() -> RootFinder<FalsePositionState, E, T> {
+    RootFinder::<FalsePositionState, E, T> {
+        state: FalsePositionState::new(),
+        init: basic_init,
+        solver: illinois_iterate,
+    }
+}
+
+/// Create new Anderson-Björck false position root finder
+pub fn new_anderson_bjorck_finder<E, T: Float>() -> RootFinder<FalsePositionState, E, T> {
+    RootFinder::<FalsePositionState, E, T> {
+        state: FalsePositionState::new(),
+        init: basic_init,
+        solver: anderson_bjorck_iterate,
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_false_position() {
+    fn f(x: f64) -> Result<f64, ()> {
+        Ok(x.powi(20) - 1.0)
+    }
+
+    // Plain false position stalls on convex function
+    let res = new_false_position_finder().find(f, 0.1, 2.0, 1.0e-15, 100)
+        .expect("root finder failed");
+    assert!(res.nr_iterations == 100);
+
+    let res = new_illinois_finder().find(f, 0.1, 2.0, 1.0e-15, 100)
+        .expect("root finder failed");
+    assert!(res.nr_iterations < 100);
+    assert_f64_near!(res.root, 1.0, 4);
+
+    let res = new_anderson_bjorck_finder().find(f, 0.1, 2.0, 1.0e-15, 100)
+        .expect("root finder failed");
+    assert!(res.nr_iterations < 100);
+    assert_f64_near!(res.root, 1.0, 4);
+}
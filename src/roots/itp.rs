@@ -7,98 +7,220 @@
 //!
 //! - <https://en.wikipedia.org/wiki/ITP_method>
 //! - <https://github.com/paulnorthrop/itp>
+//! - I.F.D. Oliveira, R.H.C. Takahashi, An Enhancement of the Bisection Method
+//!   Average Performance Preserving Minmax Optimality, ACM TOMS 47(1), 2020
 
-use super::{RootsErr, Range, /*FnWithRoots,*/ RootFinder, RootFinderState};
+use num_traits::float::Float;
+use super::{RootsErr, Range, FnWithRoots, RootFinder, RootFinderState, cast, is_same_sign,
+    basic_init};
 use super::bisection::{BisectionState, bisection_iterate};
 
 
-/// ITP state that is transferred between iteration
-pub struct ItpState {}
+/// ITP state keeps method parameters, initial range and iteration counter
+///
+/// Parameters:
+///
+/// - `k1 > 0` and `1 <= k2 < 1 + golden ratio` control truncation `delta = k1*(b - a)^k2`,
+/// - `n0 >= 0` is a number of iterations allowed on top of bisection worst case,
+/// - `epsilon > 0` is a target, the middle of the range is within `epsilon` from the root
+///   after at most `n_max = ceil(log2((b0 - a0)/(2*epsilon))) + n0` iterations.
+///
+/// Default parameters are `k1 = 0.2/(b0 - a0)`, `k2 = 2`, `n0 = 1`
+/// and `epsilon = T::epsilon()*max(|a0|, |b0|, 1)`.
+#[derive(Clone)]
+pub struct ItpState<T = f64> {
+    k1: T,
+    k2: T,
+    n0: usize,
+    epsilon: T,
+    initial_range: (T, T),
+    n_max: usize,
+    iteration: usize,
+    started: bool,
+}
+
+impl<T: Float> RootFinderState for ItpState<T> {
+    fn new() -> ItpState<T> {
+        ItpState {
+            k1: T::nan(),
+            k2: cast(2.0),
+            n0: 1,
+            epsilon: T::nan(),
+            initial_range: (T::nan(), T::nan()),
+            n_max: 0,
+            iteration: 0,
+            started: false,
+        }
+    }
+}
+
+impl<T: Float> ItpState<T> {
+    /// State with user chosen parameters, NaN `k1` or `epsilon` selects the default
+    pub fn with_params(k1: T, k2: T, n0: usize, epsilon: T) -> ItpState<T> {
+        ItpState {k1, k2, n0, epsilon, ..ItpState::new()}
+    }
+
+    /// Truncation factor `k1`
+    pub fn k1(&self) -> T { self.k1 }
+
+    /// Truncation exponent `k2`
+    pub fn k2(&self) -> T { self.k2 }
 
-impl RootFinderState for ItpState {
-    fn new() -> ItpState { ItpState{} }
+    /// Number of iterations on top of bisection worst case
+    pub fn n0(&self) -> usize { self.n0 }
+
+    /// Target precision
+    pub fn epsilon(&self) -> T { self.epsilon }
+
+    /// Range `(a0, b0)` the iterations started from
+    pub fn initial_range(&self) -> (T, T) { self.initial_range }
+
+    /// Worst case number of iterations `n_max` to reach `epsilon`
+    pub fn max_iterations(&self) -> usize { self.n_max }
+
+    /// Number of performed iterations
+    pub fn iteration(&self) -> usize { self.iteration }
+
+    /// Resolve default parameters from the initial range and check them
+    fn start<E>(mut self, x_left: T, x_right: T) -> Result<ItpState<T>, RootsErr<E>> {
+        let two: T = cast(2.0);
+        let width = x_right - x_left;
+        let golden_ratio: T = cast((1.0 + 5.0f64.sqrt()) / 2.0);
+
+        if self.k1.is_nan() {
+            self.k1 = if width > T::zero() { cast::<T>(0.2) / width } else { cast(0.1) };
+        }
+        if self.epsilon.is_nan() {
+            self.epsilon = T::epsilon() * x_left.abs().max(x_right.abs()).max(T::one());
+        }
+
+        let valid = self.k1 > T::zero()
+            && self.k2 >= T::one() && self.k2 < T::one() + golden_ratio
+            && self.epsilon > T::zero() && self.epsilon.is_finite();
+        if !valid {
+            return Err(RootsErr::BadTolerance);
+        }
+
+        let n_half = (width / (two * self.epsilon)).log2().ceil().max(T::zero());
+        self.n_max = n_half.to_usize().unwrap_or(0) + self.n0;
+        self.initial_range = (x_left, x_right);
+        self.iteration = 0;
+        self.started = true;
+
+        Ok(self)
+    }
 }
 
 /// Bisect with [Interpolate Truncate and Project](https://en.wikipedia.org/wiki/ITP_method)
 ///
-/// <https://github.com/paulnorthrop/itp>
+/// Each iteration interpolates with regula falsi, truncates the estimate
+/// towards the middle by `delta = k1*(b - a)^k2` and projects it into
+/// the interval of radius `r = epsilon*2^(n_max - j) - (b - a)/2` around the middle,
+/// where `j` is the iteration number. The projection guarantees that the range
+/// is not wider than `2*epsilon`, up to rounding of the endpoints, after `n_max` iterations,
+/// so ITP is never worse than bisection by more than `n0` iterations.
+/// When the range is narrower than `2*epsilon` iterations switch to bisection.
+///
+/// Root estimate is the last evaluated point, the middle of the range
+/// is the estimate with the guaranteed `epsilon` precision.
 ///
-/// TODO check all parameters, especially k1
-/// update `for_rk *= 0.5`? see <https://github.com/paulnorthrop/itp/blob/main/src/itp_c.cpp>
+/// # Example
 ///
-pub fn itp_iterate(
-    f: fn(x: f64) -> Result<f64, ()>,
-    (x_left, x_right, f_left, f_right): Range,
-    state: ItpState,
-    ) -> Result<(f64, Range, ItpState), RootsErr>
+/// ```
+/// # use rustamath::roots::itp::new_itp_finder_with;
+/// let finder = new_itp_finder_with(0.1, 2.0, 1, 1.0e-10);
+///
+/// let mut stepper = finder.stepper(|x: f64| Ok::<f64, ()>(x.powi(3) - x - 1.0), 1.0, 2.0).unwrap();
+/// // allow rounding of the endpoints
+/// while stepper.range().1 - stepper.range().0 > 2.0e-10 + 4.0 * f64::EPSILON {
+///     stepper.iterate().unwrap();
+/// }
+/// // n_max = ceil(log2(1/2e-10)) + 1 = 34
+/// assert!(stepper.nr_iterations() <= stepper.state().max_iterations());
+/// let (a, b, _, _) = stepper.range();
+/// assert!(((a + b) / 2.0 - 1.324717957244746).abs() <= 1.0e-10);
+/// ```
+pub fn itp_iterate<E, T: Float>(
+    f: &mut FnWithRoots<'_, E, T>,
+    (x_left, x_right, f_left, f_right): Range<T>,
+    state: ItpState<T>,
+    ) -> Result<(T, Range<T>, ItpState<T>), RootsErr<E>>
 {
-    let   epsilon: f64 = 1e-10;
-    let   k1: f64 = 0.1;//0.2 / (x_right - x_left);
-    const K2: f64 = 2.0;
-    const N0: f64 = 1.0;
-    let   log2_epsilon: f64 = epsilon.log2();
-    let   log2bma = (x_right - x_left).log2();
-    let   for_rk = 2.0_f64.powf(N0 - 1.0 + log2_epsilon + (log2bma - log2_epsilon).ceil());
+    let two: T = cast(2.0);
 
+    let mut state = if state.started { state } else { state.start(x_left, x_right)? };
 
-    assert!(x_right >= x_left);
-    assert!(f_left.is_sign_positive() != f_right.is_sign_positive());
+    if f_left == T::zero() {
+        return Ok((/*root=*/x_left, (x_left, x_left, f_left, f_left), state));
+    }
 
-    if (x_right - x_left) <= 2.0*epsilon {
-        let bstate: BisectionState = BisectionState{};
-        match bisection_iterate(f, (x_left, x_right, f_left, f_right), bstate) {
-            Ok(b) => return Ok((b.0, b.1, state)),
-            Err(err) => return Err(err),
-        };
+    if f_right == T::zero() {
+        return Ok((/*root=*/x_right, (x_right, x_right, f_right, f_right), state));
     }
 
-    if f_left == 0.0 {
-        return Ok((/*root=*/x_left, (x_left, x_left, f_left, f_left), state));
+    if f_left.is_nan() || f_right.is_nan() {
+        return Err(RootsErr::NotFinite);
     }
 
-    if f_right == 0.0 {
-        return Ok((/*root=*/x_right, (x_right, x_right, f_right, f_right), state));
+    if is_same_sign(f_left, f_right) {
+        return Err(RootsErr::EndpointsNotStraddleYeq0);
+    }
+
+    let width = x_right - x_left;
+    state.iteration += 1;
+
+    if width <= two * state.epsilon {
+        let bstate: BisectionState = BisectionState{};
+        let (root, range, _) = bisection_iterate(f, (x_left, x_right, f_left, f_right), bstate)?;
+        return Ok((root, range, state));
     }
 
     // Interpolation [Regula falsi](https://en.wikipedia.org/wiki/Regula_falsi)
-    let x_interpolation = (f_right * x_left - f_left * x_right) / (f_right - f_left) ;
+    let x_interpolation = (f_right * x_left - f_left * x_right) / (f_right - f_left);
 
     // Truncation
-    let x_bisect = (x_left + x_right) / 2.0;
+    let x_bisect = (x_left + x_right) / two;
     let x_diff = x_bisect - x_interpolation;
-    let sigma: f64 = if x_diff.is_sign_negative() { -1.0 } else { 1.0 };
-    let delta = k1 * (x_right - x_left).powf(K2) ;
+    let sigma: T = if x_diff.is_sign_negative() { -T::one() } else { T::one() };
+    let delta = state.k1 * width.powf(state.k2);
     let x_trunc = if delta <= x_diff.abs() { x_interpolation + sigma * delta } else { x_bisect };
 
-    // Projection
-    let rk = for_rk - (x_right - x_left) / 2.0 ;
-    let x_itp = if x_diff.abs() <= rk { x_trunc } else { x_bisect - sigma * rk };
+    // Projection, `j = iteration - 1` iterations were done before this one
+    let power = state.n_max.saturating_sub(state.iteration - 1);
+    let rk = (state.epsilon * two.powi(power as i32) - width / two).max(T::zero());
+    let x_itp = if (x_trunc - x_bisect).abs() <= rk { x_trunc } else { x_bisect - sigma * rk };
 
     // Update range
-    let y_itp = match f(x_itp) {
-        Ok(y) => y,
-        Err(_) => return Err(RootsErr::FunctionFailed),
-    };
-
-    if y_itp.is_sign_positive() == f_right.is_sign_positive() {
-        let x_right_new = x_itp;
-        let f_right_new = y_itp;
-        let root = (x_left + x_right_new) / 2.0;
-        Ok((root, (x_left, x_right_new, f_left, f_right_new), state))
-    } else if y_itp.is_sign_positive() != f_right.is_sign_positive() {
-        let x_left_new = x_itp;
-        let f_left_new = y_itp;
-        let root = (x_left_new + x_right) / 2.0;
-        Ok((root, (x_left_new, x_right, f_left_new, f_right), state))
-    } else {
+    let y_itp = f(x_itp).map_err(RootsErr::FunctionFailed)?;
+
+    if y_itp == T::zero() {
         Ok((x_itp, (x_itp, x_itp, y_itp, y_itp), state))
+    } else if y_itp.is_sign_positive() == f_right.is_sign_positive() {
+        Ok((x_itp, (x_left, x_itp, f_left, y_itp), state))
+    } else {
+        Ok((x_itp, (x_itp, x_right, y_itp, f_right), state))
     }
 }
 
-/// Create new ITP root finder
-pub fn new_itp_finder() -> RootFinder<ItpState> {
-    RootFinder::<ItpState> {
-        state: ItpState {},
+/// Create new ITP root finder with default parameters
+pub fn new_itp_finder<E, T: Float>() -> RootFinder<ItpState<T>, E, T> {
+    RootFinder::<ItpState<T>, E, T> {
+        state: ItpState::new(),
+        init: basic_init,
+        solver: itp_iterate,
+    }
+}
+
+/// Create new ITP root finder with user chosen parameters, see [`ItpState`]
+pub fn new_itp_finder_with<E, T: Float>(
+    k1: T,
+    k2: T,
+    n0: usize,
+    epsilon: T
+) -> RootFinder<ItpState<T>, E, T> {
+    RootFinder::<ItpState<T>, E, T> {
+        state: ItpState::with_params(k1, k2, n0, epsilon),
+        init: basic_init,
         solver: itp_iterate,
     }
 }
@@ -122,4 +244,30 @@ fn test_itp() {
     else {
         assert!(false, "root finder failed");
     }
+
+    // worst case bound holds for user chosen parameters
+    for (k1, k2, n0) in [(0.1, 2.0, 0), (0.2, 1.0, 1), (1.0, 2.5, 3)] {
+        let finder = new_itp_finder_with(k1, k2, n0, 1.0e-12);
+        let mut stepper = finder.stepper(sin, 3.0, 4.0).expect("root finder failed");
+        while stepper.range().1 - stepper.range().0 > 2.0e-12 + 16.0 * f64::EPSILON {
+            stepper.iterate().expect("root finder failed");
+        }
+        // ceil(log2(1/2e-12)) = 39
+        assert_eq!(stepper.state().max_iterations(), 39 + n0);
+        assert!(stepper.nr_iterations() <= stepper.state().max_iterations());
+        let (a, b, _, _) = stepper.range();
+        assert!(((a + b) / 2.0 - std::f64::consts::PI).abs() <= 1.0e-12);
+    }
+
+    // reversed range, endpoints that do not straddle y=0 or are NaN
+    let res = finder.find(sin, 4.0, 3.0, 1.0e-15, 50).expect("root finder failed");
+    assert_f64_near!(res.root, std::f64::consts::PI, 4);
+    let res = finder.find(sin, 1.0, 2.0, 1.0e-15, 50);
+    assert!(matches!(res, Err(RootsErr::EndpointsNotStraddleYeq0)));
+    let res = finder.find(|x: f64| Ok(if x > 3.5 { f64::NAN } else { x.sin() }), 3.0, 4.0, 1.0e-15, 50);
+    assert!(matches!(res, Err(RootsErr::NotFinite)));
+
+    // k2 must be less than 1 + golden ratio
+    let res = new_itp_finder_with(0.1, 3.0, 1, 1.0e-12).find(sin, 3.0, 4.0, 1.0e-15, 50);
+    assert!(matches!(res, Err(RootsErr::BadTolerance)));
 }
\ No newline at end of file
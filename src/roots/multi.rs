@@ -0,0 +1,264 @@
+//! Multidimensional root finding, solve system of nonlinear equations `F(x) = 0`.
+//!
+//! (c) Igor Lesik 2023
+//! MIT license
+//!
+//! References:
+//!
+//! - <https://www.gnu.org/software/gsl/doc/html/multiroots.html>
+//! - <https://github.com/ampl/gsl/tree/master/multiroots>
+//! - J.E. Dennis, R.B. Schnabel - Numerical Methods for Unconstrained Optimization
+//!   and Nonlinear Equations
+
+use crate::la::tnsr::{Tnsr, Tensor, Matrix};
+use crate::la::gauss;
+use super::{RootsErr, RootFinderState};
+
+pub mod newton;
+pub mod broyden;
+pub mod hybrid;
+pub mod convergence;
+pub mod stepper;
+
+pub use self::convergence::MultiConvergence;
+pub use self::stepper::{MultiStep, MultiStepper};
+
+#[cfg(test)]
+mod tests;
+
+/// System of functions, writes `F(x)` into the second argument
+pub type FnVec<'a, E> = dyn FnMut(&Tnsr<f64>, &mut Tnsr<f64>) -> Result<(), E> + 'a;
+
+/// Jacobian of the system, writes `J(i,j) = dF(i)/dx(j)` into the second argument
+pub type FnJacobian<'a, E> = dyn FnMut(&Tnsr<f64>, &mut Tnsr<f64>) -> Result<(), E> + 'a;
+
+/// System of nonlinear equations with analytic or finite difference Jacobian
+///
+/// # Example
+///
+/// ```
+/// # use rustamath::la::tnsr::{Tnsr, Matrix};
+/// # use rustamath::roots::multi::MultiFn;
+/// // x^2 + y^2 = 4, x = y
+/// let f = |x: &Tnsr<f64>, f: &mut Tnsr<f64>| {
+///     f.v[0] = x.v[0]*x.v[0] + x.v[1]*x.v[1] - 4.0;
+///     f.v[1] = x.v[0] - x.v[1];
+///     Ok::<(), ()>(())
+/// };
+/// let df = |x: &Tnsr<f64>, j: &mut Tnsr<f64>| {
+///     j.set(0, 0, 2.0*x.v[0]).set(0, 1, 2.0*x.v[1]);
+///     j.set(1, 0, 1.0).set(1, 1, -1.0);
+///     Ok::<(), ()>(())
+/// };
+/// let with_jacobian = MultiFn::with_jacobian(f, df);
+/// let finite_differences = MultiFn::new(f);
+/// ```
+pub struct MultiFn<'a, E = ()> {
+    f: Box<FnVec<'a, E>>,
+    df: Option<Box<FnJacobian<'a, E>>>,
+}
+
+impl<'a, E> MultiFn<'a, E> {
+    /// System with Jacobian approximated by forward differences
+    pub fn new<F>(f: F) -> Self
+    where
+        F: FnMut(&Tnsr<f64>, &mut Tnsr<f64>) -> Result<(), E> + 'a
+    {
+        MultiFn {f: Box::new(f), df: None}
+    }
+
+    /// System with analytic Jacobian
+    pub fn with_jacobian<F, J>(f: F, df: J) -> Self
+    where
+        F: FnMut(&Tnsr<f64>, &mut Tnsr<f64>) -> Result<(), E> + 'a,
+        J: FnMut(&Tnsr<f64>, &mut Tnsr<f64>) -> Result<(), E> + 'a
+    {
+        MultiFn {f: Box::new(f), df: Some(Box::new(df))}
+    }
+
+    /// Return true if Jacobian is analytic
+    pub fn has_jacobian(&self) -> bool {
+        self.df.is_some()
+    }
+
+    /// Evaluate `F(x)`
+    pub fn eval(&mut self, x: &Tnsr<f64>, fx: &mut Tnsr<f64>) -> Result<(), RootsErr<E>> {
+        (self.f)(x, fx).map_err(RootsErr::FunctionFailed)
+    }
+
+    /// Evaluate Jacobian at `x`, `fx = F(x)` is used by finite differences
+    pub fn jacobian(
+        &mut self,
+        x: &Tnsr<f64>,
+        fx: &Tnsr<f64>,
+        j: &mut Tnsr<f64>
+    ) -> Result<(), RootsErr<E>>
+    {
+        match self.df.as_mut() {
+            Some(df) => df(x, j).map_err(RootsErr::FunctionFailed),
+            None => fd_jacobian(&mut self.f, x, fx, j),
+        }
+    }
+}
+
+/// Approximate Jacobian with forward differences
+///
+/// `J(i,j) = (F(i)(x + h*e(j)) - F(i)(x)) / h` where `h = sqrt(ε)*|x(j)|`,
+/// or `h = sqrt(ε)` if `x(j) = 0`, like GSL `gsl_multiroot_fdjacobian`.
+pub fn fd_jacobian<E>(
+    f: &mut FnVec<'_, E>,
+    x: &Tnsr<f64>,
+    fx: &Tnsr<f64>,
+    j: &mut Tnsr<f64>
+) -> Result<(), RootsErr<E>>
+{
+    let n = x.v.len();
+    let epsilon = f64::EPSILON.sqrt();
+
+    let mut xh = x.clone();
+    let mut fh = Tnsr::<f64>::new_vector(fx.v.len());
+
+    for col in 0..n {
+        let h = if x.v[col] == 0.0 { epsilon } else { epsilon * x.v[col].abs() };
+        xh.v[col] = x.v[col] + h;
+        f(&xh, &mut fh).map_err(RootsErr::FunctionFailed)?;
+        xh.v[col] = x.v[col];
+        // use actual step to reduce rounding error
+        let h = (x.v[col] + h) - x.v[col];
+        for row in 0..fx.v.len() {
+            j.set(row, col, (fh.v[row] - fx.v[row]) / h);
+        }
+    }
+
+    Ok(())
+}
+
+/// Current point of multidimensional root finding iterations
+#[derive(Clone, Debug)]
+pub struct MultiPoint {
+    /// Root estimate
+    pub x: Tnsr<f64>,
+    /// Function value `F(x)`
+    pub f: Tnsr<f64>,
+    /// Last step
+    pub dx: Tnsr<f64>,
+}
+
+/// Solver function that performs one iteration and updates the point
+pub type MultiSolver<S, E> = fn(
+    &mut MultiFn<'_, E>,
+    &mut MultiPoint,
+    S,
+) -> Result<S, RootsErr<E>>;
+
+/// Multidimensional root finder performs root finding iterations
+pub struct MultiRootFinder<S, E = ()> {
+    /// State that gets transferred between iterations
+    pub state: S,
+    solver: MultiSolver<S, E>,
+}
+
+/// Root found value, number of iterations and etc.
+#[derive(Clone, Debug)]
+pub struct MultiRootResult {
+    /// Root found value
+    pub x: Tnsr<f64>,
+    /// Function value at the root
+    pub f: Tnsr<f64>,
+    /// Number of iterations
+    pub nr_iterations: usize,
+    /// Criterion that stopped iterations, `None` if `max_iterations` was exhausted
+    pub converged_by: Option<MultiConvergence>,
+    /// Iterations stopped because `max_iterations` was reached
+    pub max_iterations_reached: bool,
+}
+
+/// Check initial guess and evaluate function at it
+pub fn multi_init<E>(
+    system: &mut MultiFn<'_, E>,
+    x: &Tnsr<f64>) -> Result<MultiPoint, RootsErr<E>>
+{
+    if x.nr_dims() != 1 || x.v.is_empty() {
+        return Err(RootsErr::DimensionsMismatch);
+    }
+
+    let n = x.v.len();
+    let mut f = Tnsr::<f64>::new_vector(n);
+    system.eval(x, &mut f)?;
+
+    Ok(MultiPoint {x: x.clone(), f, dx: Tnsr::<f64>::new_vector(n)})
+}
+
+impl<S: RootFinderState, E> MultiRootFinder<S, E> {
+
+    /// Call solvers till any of convergence criteria is met
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustamath::la::tnsr::Tnsr;
+    /// # use rustamath::roots::multi::{MultiFn, MultiConvergence, hybrid::new_hybrid_finder};
+    /// # use assert_float_eq::*;
+    /// let system = MultiFn::new(|x: &Tnsr<f64>, f: &mut Tnsr<f64>| {
+    ///     f.v[0] = 1.0 - x.v[0];
+    ///     f.v[1] = 10.0 * (x.v[1] - x.v[0]*x.v[0]);
+    ///     Ok::<(), ()>(())
+    /// });
+    /// let mut x0 = Tnsr::<f64>::new_vector(2);
+    /// x0.v = vec![-10.0, -5.0];
+    /// let res = new_hybrid_finder().find(system, &x0,
+    ///     &[MultiConvergence::Residual{epsabs: 1.0e-10}], 100).unwrap();
+    /// assert_float_absolute_eq!(res.x.v[0], 1.0, 1.0e-9);
+    /// assert_float_absolute_eq!(res.x.v[1], 1.0, 1.0e-9);
+    /// ```
+    pub fn find(
+        &self,
+        system: MultiFn<'_, E>,
+        x: &Tnsr<f64>,
+        criteria: &[MultiConvergence],
+        max_iterations: usize
+    ) -> Result<MultiRootResult, RootsErr<E>>
+    {
+        let mut stepper = self.stepper(system, x)?;
+        stepper::run(&mut stepper, criteria, max_iterations, None)
+    }
+}
+
+/// Make vector from slice
+fn to_vector(v: &[f64]) -> Tnsr<f64> {
+    let mut t = Tnsr::<f64>::new_vector(v.len());
+    t.v.copy_from_slice(v);
+    t
+}
+
+/// Euclidean norm
+fn enorm(v: &[f64]) -> f64 {
+    v.iter().fold(0.0, |acc, x| acc + x*x).sqrt()
+}
+
+/// Matrix-vector product `A*x`
+fn mat_vec(a: &Tnsr<f64>, x: &[f64]) -> Vec<f64> {
+    (0..a.nr_rows())
+        .map(|row| (0..a.nr_cols()).map(|col| Matrix::get(a, row, col) * x[col]).sum())
+        .collect()
+}
+
+/// Transposed matrix-vector product `Aᵀ*x`
+fn mat_t_vec(a: &Tnsr<f64>, x: &[f64]) -> Vec<f64> {
+    (0..a.nr_cols())
+        .map(|col| (0..a.nr_rows()).map(|row| Matrix::get(a, row, col) * x[row]).sum())
+        .collect()
+}
+
+/// Solve `A*x = b` with Gaussian elimination and partial pivoting,
+/// return `None` if `A` is singular
+fn solve_linear(a: &Tnsr<f64>, b: &[f64]) -> Option<Vec<f64>> {
+    let n = b.len();
+    if a.nr_rows() != n || a.nr_cols() != n {
+        return None;
+    }
+
+    let mut m: Vec<f64> = (0..n*n).map(|k| Matrix::get(a, k / n, k % n)).collect();
+    let mut x = b.to_vec();
+    gauss::solve_linear(&mut m, &mut x, n)
+}
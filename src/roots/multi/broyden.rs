@@ -0,0 +1,178 @@
+//! Broyden method for systems of nonlinear equations.
+//!
+//! (c) Igor Lesik 2023
+//! MIT license
+//!
+//! References:
+//!
+//! - <https://en.wikipedia.org/wiki/Broyden%27s_method>
+//! - <https://github.com/ampl/gsl/blob/master/multiroots/broyden.c>
+
+use crate::la::tnsr::{Tnsr, Matrix};
+use super::{RootsErr, RootFinderState, MultiFn, MultiPoint, MultiRootFinder,
+    solve_linear, to_vector, enorm, mat_vec, mat_t_vec};
+
+/// Broyden state keeps approximation of the inverse Jacobian
+#[derive(Clone)]
+pub struct BroydenState {
+    inv_jacobian: Option<Tnsr<f64>>,
+    fresh: bool,
+}
+
+impl RootFinderState for BroydenState {
+    fn new() -> BroydenState {
+        BroydenState{inv_jacobian: None, fresh: false}
+    }
+}
+
+impl BroydenState {
+    /// Approximation of the inverse Jacobian at the current point
+    pub fn inv_jacobian(&self) -> Option<&Tnsr<f64>> {
+        self.inv_jacobian.as_ref()
+    }
+}
+
+/// Compute Jacobian at the point and invert it
+fn inverse_jacobian<E>(
+    system: &mut MultiFn<'_, E>,
+    point: &MultiPoint,
+    ) -> Result<Tnsr<f64>, RootsErr<E>>
+{
+    let n = point.x.v.len();
+
+    let mut j = Tnsr::<f64>::new_matrix(n, n);
+    system.jacobian(&point.x, &point.f, &mut j)?;
+
+    let mut inv = Tnsr::<f64>::new_matrix(n, n);
+    let mut e = vec![0.0; n];
+
+    for col in 0..n {
+        e[col] = 1.0;
+        let inv_col = solve_linear(&j, &e).ok_or(RootsErr::SingularJacobian)?;
+        e[col] = 0.0;
+        for (row, val) in inv_col.into_iter().enumerate() {
+            inv.set(row, col, val);
+        }
+    }
+
+    Ok(inv)
+}
+
+/// Broyden step `dx = -t*H*F(x)` where `H` approximates inverse Jacobian
+///
+/// Step length `t` is reduced while `|F(x + dx)| > |F(x)|`, like in GSL `broyden`.
+/// If the reduced step is too small, `H` is recomputed from the Jacobian.
+/// After the step `H` is updated with Sherman-Morrison formula
+/// `H += (dx - H*df)*(dxᵀ*H)/(dxᵀ*H*df)` where `df = F(x + dx) - F(x)`.
+pub fn broyden_iterate<E>(
+    system: &mut MultiFn<'_, E>,
+    point: &mut MultiPoint,
+    state: BroydenState,
+    ) -> Result<BroydenState, RootsErr<E>>
+{
+    let n = point.x.v.len();
+
+    let (mut h, mut fresh) = match state.inv_jacobian {
+        Some(h) if h.nr_rows() == n => (h, state.fresh),
+        _ => (inverse_jacobian(system, point)?, true),
+    };
+
+    let phi0 = enorm(&point.f.v);
+    let min_t = f64::EPSILON.sqrt();
+
+    let (dx, x, f) = loop {
+        let p: Vec<f64> = mat_vec(&h, &point.f.v).iter().map(|v| -v).collect();
+
+        let mut t = 1.0;
+        let mut f = Tnsr::<f64>::new_vector(n);
+
+        let accepted = loop {
+            let dx: Vec<f64> = p.iter().map(|p| t * p).collect();
+            let x: Vec<f64> = point.x.v.iter().zip(&dx).map(|(x, dx)| x + dx).collect();
+            let x = to_vector(&x);
+            system.eval(&x, &mut f)?;
+
+            let phi1 = enorm(&f.v);
+            if phi1 <= phi0 {
+                break Some((dx, x));
+            }
+            if t < min_t {
+                break None;
+            }
+
+            // full step goes uphill, take a reduced step instead
+            let u = if phi1.is_finite() {
+                let theta = phi1 / phi0;
+                ((1.0 + 6.0 * theta).sqrt() - 1.0) / (3.0 * theta)
+            } else {
+                0.5
+            };
+            t *= u.max(0.1);
+        };
+
+        match accepted {
+            Some((dx, x)) => break (dx, x, f),
+            None if fresh => return Err(RootsErr::NoProgress),
+            None => {
+                h = inverse_jacobian(system, point)?;
+                fresh = true;
+            }
+        }
+    };
+
+    let df: Vec<f64> = f.v.iter().zip(&point.f.v).map(|(f1, f0)| f1 - f0).collect();
+    let h_df = mat_vec(&h, &df);
+    let dx_h = mat_t_vec(&h, &dx);
+    let denom: f64 = dx.iter().zip(&h_df).map(|(a, b)| a * b).sum();
+
+    if denom != 0.0 && denom.is_finite() {
+        for row in 0..n {
+            let u = (dx[row] - h_df[row]) / denom;
+            for (col, dx_h) in dx_h.iter().enumerate() {
+                let val = Matrix::get(&h, row, col) + u * dx_h;
+                h.set(row, col, val);
+            }
+        }
+        fresh = false;
+    }
+
+    point.x = x;
+    point.f = f;
+    point.dx = to_vector(&dx);
+
+    Ok(BroydenState{inv_jacobian: Some(h), fresh})
+}
+
+/// Create new Broyden root finder
+pub fn new_broyden_finder<E>() -> MultiRootFinder<BroydenState, E> {
+    MultiRootFinder::<BroydenState, E> {
+        state: BroydenState::new(),
+        solver: broyden_iterate,
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_broyden() {
+    use super::MultiConvergence;
+
+    // x^2 + y^2 = 4, x = y
+    let f = |x: &Tnsr<f64>, f: &mut Tnsr<f64>| -> Result<(), ()> {
+        f.v[0] = x.v[0]*x.v[0] + x.v[1]*x.v[1] - 4.0;
+        f.v[1] = x.v[0] - x.v[1];
+        Ok(())
+    };
+
+    let mut x0 = Tnsr::<f64>::new_vector(2);
+    x0.v = vec![1.0, 0.5];
+
+    let finder = new_broyden_finder();
+
+    let res = finder.find(MultiFn::new(f), &x0,
+        &[MultiConvergence::Residual{epsabs: 1.0e-14}], 50)
+        .expect("root finder failed");
+
+    assert!(res.nr_iterations < 20);
+    assert_f64_near!(res.x.v[0], std::f64::consts::SQRT_2, 8);
+    assert_f64_near!(res.x.v[1], std::f64::consts::SQRT_2, 8);
+}
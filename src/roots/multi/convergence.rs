@@ -0,0 +1,72 @@
+//! Convergence criteria (stopping tests) for multidimensional root finding.
+//!
+//! (c) Igor Lesik 2023
+//! MIT license
+//!
+//! References:
+//!
+//! - <https://github.com/ampl/gsl/blob/master/multiroots/convergence.c>
+//! - <https://www.gnu.org/software/gsl/doc/html/multiroots.html#search-stopping-parameters>
+
+use super::MultiPoint;
+
+/// Criterion that stops multidimensional root finding iterations
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MultiConvergence {
+    /// Last step is small enough in every component, see [`delta_converged`]
+    Delta {
+        /// Absolute tolerance
+        epsabs: f64,
+        /// Relative tolerance
+        epsrel: f64
+    },
+    /// Function values are small enough, see [`residual_converged`]
+    Residual {
+        /// Absolute tolerance
+        epsabs: f64
+    },
+}
+
+impl MultiConvergence {
+    /// Return true if tolerances are not negative
+    pub fn is_valid(&self) -> bool {
+        match *self {
+            MultiConvergence::Delta {epsabs, epsrel} => epsabs >= 0.0 && epsrel >= 0.0,
+            MultiConvergence::Residual {epsabs} => epsabs >= 0.0,
+        }
+    }
+
+    /// Check the criterion
+    pub fn is_met(&self, point: &MultiPoint) -> bool {
+        match *self {
+            MultiConvergence::Delta {epsabs, epsrel} =>
+                delta_converged(&point.dx.v, &point.x.v, epsabs, epsrel),
+            MultiConvergence::Residual {epsabs} =>
+                residual_converged(&point.f.v, epsabs),
+        }
+    }
+}
+
+/// Return first criterion that is met
+pub fn first_met(criteria: &[MultiConvergence], point: &MultiPoint) -> Option<MultiConvergence> {
+    criteria.iter().find(|c| c.is_met(point)).copied()
+}
+
+/// Test step `|dx(i)| < epsabs + epsrel * |x(i)|` for every component
+///
+/// # Example
+///
+/// ```
+/// # use rustamath::roots::multi::convergence::*;
+/// assert!(delta_converged(&[1.0e-9, 0.0], &[1.0, 2.0], 1.0e-8, 0.0));
+/// assert!(!delta_converged(&[1.0e-9, 1.0e-3], &[1.0, 2.0], 1.0e-8, 0.0));
+/// ```
+pub fn delta_converged(dx: &[f64], x: &[f64], epsabs: f64, epsrel: f64) -> bool {
+    dx.iter().zip(x).all(|(dx, x)| *dx == 0.0 || dx.abs() < epsabs + epsrel * x.abs())
+}
+
+/// Test residual `sum(|f(i)|) < epsabs`, exact root `f = 0` is always converged
+pub fn residual_converged(f: &[f64], epsabs: f64) -> bool {
+    let sum = f.iter().fold(0.0, |acc, f| acc + f.abs());
+    sum == 0.0 || sum < epsabs
+}
@@ -0,0 +1,205 @@
+//! Powell hybrid (dogleg) method for systems of nonlinear equations.
+//!
+//! (c) Igor Lesik 2023
+//! MIT license
+//!
+//! References:
+//!
+//! - M.J.D. Powell - A Hybrid Method for Nonlinear Equations (1970)
+//! - <https://github.com/ampl/gsl/blob/master/multiroots/hybrid.c>
+//! - <https://en.wikipedia.org/wiki/Powell%27s_dog_leg_method>
+
+use crate::la::tnsr::{Tnsr, Matrix};
+use super::{RootsErr, RootFinderState, MultiFn, MultiPoint, MultiRootFinder,
+    solve_linear, to_vector, enorm, mat_vec, mat_t_vec};
+
+/// Initial trust region radius factor, like MINPACK `factor`
+const TRUST_FACTOR: f64 = 100.0;
+
+/// Hybrid state keeps the Jacobian at the current point and trust region radius
+#[derive(Clone)]
+pub struct HybridState {
+    jacobian: Option<Tnsr<f64>>,
+    delta: f64,
+}
+
+impl RootFinderState for HybridState {
+    fn new() -> HybridState {
+        HybridState{jacobian: None, delta: f64::NAN}
+    }
+}
+
+impl HybridState {
+    /// Jacobian at the current point
+    pub fn jacobian(&self) -> Option<&Tnsr<f64>> {
+        self.jacobian.as_ref()
+    }
+
+    /// Trust region radius
+    pub fn delta(&self) -> f64 {
+        self.delta
+    }
+}
+
+/// Dogleg step inside trust region `|dx| <= delta`
+///
+/// Newton step `J*dx = -F` is taken if it is inside the region,
+/// otherwise the step goes along steepest descent `g = -Jᵀ*F`
+/// to the Cauchy point and then towards the Newton step till the region boundary.
+fn dogleg(j: &Tnsr<f64>, f: &[f64], delta: f64) -> Option<Vec<f64>> {
+    let minus_f: Vec<f64> = f.iter().map(|f| -f).collect();
+    let newton = solve_linear(j, &minus_f);
+
+    if let Some(newton) = newton.as_ref() {
+        if enorm(newton) <= delta {
+            return Some(newton.clone());
+        }
+    }
+
+    let g = mat_t_vec(j, &minus_f);
+    let g_norm = enorm(&g);
+    let jg_norm = enorm(&mat_vec(j, &g));
+
+    if g_norm == 0.0 || jg_norm == 0.0 {
+        return None;
+    }
+
+    let alpha = (g_norm / jg_norm).powi(2);
+    let cauchy: Vec<f64> = g.iter().map(|g| alpha * g).collect();
+
+    if alpha * g_norm >= delta {
+        return Some(g.iter().map(|g| delta * g / g_norm).collect());
+    }
+
+    let newton = match newton {
+        Some(newton) => newton,
+        None => return Some(cauchy),
+    };
+
+    // find beta such that |cauchy + beta*(newton - cauchy)| = delta
+    let d: Vec<f64> = newton.iter().zip(&cauchy).map(|(n, c)| n - c).collect();
+    let a: f64 = d.iter().map(|d| d * d).sum();
+    let b: f64 = 2.0 * cauchy.iter().zip(&d).map(|(c, d)| c * d).sum::<f64>();
+    let c: f64 = cauchy.iter().map(|c| c * c).sum::<f64>() - delta * delta;
+    let beta = (-b + (b * b - 4.0 * a * c).max(0.0).sqrt()) / (2.0 * a);
+
+    Some(cauchy.iter().zip(&d).map(|(c, d)| c + beta * d).collect())
+}
+
+/// Powell hybrid iteration with dogleg trust region step
+///
+/// Trial steps are repeated with smaller trust region till the actual
+/// reduction of `|F|²` is at least small fraction of reduction predicted by the linear model.
+/// Trust region grows when the model predicts the reduction well.
+/// Jacobian is recomputed after each accepted step.
+pub fn hybrid_iterate<E>(
+    system: &mut MultiFn<'_, E>,
+    point: &mut MultiPoint,
+    state: HybridState,
+    ) -> Result<HybridState, RootsErr<E>>
+{
+    let n = point.x.v.len();
+
+    let (j, mut delta) = match state.jacobian {
+        Some(j) if j.nr_rows() == n => (j, state.delta),
+        _ => {
+            let mut j = Tnsr::<f64>::new_matrix(n, n);
+            system.jacobian(&point.x, &point.f, &mut j)?;
+            let x_norm = enorm(&point.x.v);
+            (j, if x_norm > 0.0 { TRUST_FACTOR * x_norm } else { TRUST_FACTOR })
+        }
+    };
+
+    let f_norm = enorm(&point.f.v);
+
+    if f_norm == 0.0 {
+        point.dx = Tnsr::<f64>::new_vector(n);
+        return Ok(HybridState{jacobian: Some(j), delta});
+    }
+
+    let mut f = Tnsr::<f64>::new_vector(n);
+
+    let (dx, x) = loop {
+        let dx = dogleg(&j, &point.f.v, delta).ok_or(RootsErr::NoProgress)?;
+        let dx_norm = enorm(&dx);
+
+        let x: Vec<f64> = point.x.v.iter().zip(&dx).map(|(x, dx)| x + dx).collect();
+        let x = to_vector(&x);
+        system.eval(&x, &mut f)?;
+
+        let predicted: Vec<f64> = mat_vec(&j, &dx).iter().zip(&point.f.v)
+            .map(|(jdx, f)| f + jdx).collect();
+
+        let actual_reduction = 1.0 - (enorm(&f.v) / f_norm).powi(2);
+        let predicted_reduction = 1.0 - (enorm(&predicted) / f_norm).powi(2);
+
+        let ratio = if predicted_reduction > 0.0 && actual_reduction.is_finite() {
+            actual_reduction / predicted_reduction
+        } else {
+            0.0
+        };
+
+        if ratio < 0.1 {
+            delta = 0.5 * dx_norm.min(delta);
+        }
+        else if ratio >= 0.75 {
+            delta = delta.max(2.0 * dx_norm);
+        }
+
+        if ratio >= 1.0e-4 {
+            break (dx, x);
+        }
+
+        if delta <= f64::EPSILON * enorm(&point.x.v) || delta == 0.0 {
+            return Err(RootsErr::NoProgress);
+        }
+    };
+
+    let mut j = j;
+    system.jacobian(&x, &f, &mut j)?;
+
+    point.x = x;
+    point.f = f;
+    point.dx = to_vector(&dx);
+
+    Ok(HybridState{jacobian: Some(j), delta})
+}
+
+/// Create new Powell hybrid root finder
+pub fn new_hybrid_finder<E>() -> MultiRootFinder<HybridState, E> {
+    MultiRootFinder::<HybridState, E> {
+        state: HybridState::new(),
+        solver: hybrid_iterate,
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_hybrid() {
+    use super::MultiConvergence;
+
+    // Rosenbrock system, root at (1, 1)
+    let f = |x: &Tnsr<f64>, f: &mut Tnsr<f64>| -> Result<(), ()> {
+        f.v[0] = 1.0 - x.v[0];
+        f.v[1] = 10.0 * (x.v[1] - x.v[0]*x.v[0]);
+        Ok(())
+    };
+    let df = |x: &Tnsr<f64>, j: &mut Tnsr<f64>| -> Result<(), ()> {
+        j.set(0, 0, -1.0).set(0, 1, 0.0);
+        j.set(1, 0, -20.0*x.v[0]).set(1, 1, 10.0);
+        Ok(())
+    };
+
+    let mut x0 = Tnsr::<f64>::new_vector(2);
+    x0.v = vec![-10.0, -5.0];
+
+    let finder = new_hybrid_finder();
+
+    let res = finder.find(MultiFn::with_jacobian(f, df), &x0,
+        &[MultiConvergence::Residual{epsabs: 1.0e-12}], 100)
+        .expect("root finder failed");
+
+    assert!(res.nr_iterations < 50);
+    assert_f64_near!(res.x.v[0], 1.0, 8);
+    assert_f64_near!(res.x.v[1], 1.0, 8);
+}
@@ -0,0 +1,112 @@
+//! Newton method for systems of nonlinear equations.
+//!
+//! (c) Igor Lesik 2023
+//! MIT license
+//!
+//! References:
+//!
+//! - <https://github.com/ampl/gsl/blob/master/multiroots/newton.c>
+//! - <https://github.com/ampl/gsl/blob/master/multiroots/dnewton.c>
+
+use crate::la::tnsr::{Tnsr, Matrix};
+use super::{RootsErr, RootFinderState, MultiFn, MultiPoint, MultiRootFinder,
+    solve_linear, to_vector};
+
+/// Newton state keeps the Jacobian between iterations
+#[derive(Clone)]
+pub struct NewtonState {
+    jacobian: Option<Tnsr<f64>>,
+}
+
+impl RootFinderState for NewtonState {
+    fn new() -> NewtonState {
+        NewtonState{jacobian: None}
+    }
+}
+
+impl NewtonState {
+    /// Jacobian at the point before the last step
+    pub fn jacobian(&self) -> Option<&Tnsr<f64>> {
+        self.jacobian.as_ref()
+    }
+}
+
+/// Newton step `J(x)*dx = -F(x)`, `x_next = x + dx`
+///
+/// Jacobian is analytic or approximated by finite differences (GSL `dnewton`),
+/// depending on how [`MultiFn`] was created.
+pub fn newton_iterate<E>(
+    system: &mut MultiFn<'_, E>,
+    point: &mut MultiPoint,
+    mut state: NewtonState,
+    ) -> Result<NewtonState, RootsErr<E>>
+{
+    let n = point.x.v.len();
+
+    let mut j = match state.jacobian.take() {
+        Some(j) if j.nr_rows() == n => j,
+        _ => Tnsr::<f64>::new_matrix(n, n),
+    };
+
+    system.jacobian(&point.x, &point.f, &mut j)?;
+
+    let minus_f: Vec<f64> = point.f.v.iter().map(|f| -f).collect();
+    let dx = solve_linear(&j, &minus_f).ok_or(RootsErr::SingularJacobian)?;
+
+    let x: Vec<f64> = point.x.v.iter().zip(&dx).map(|(x, dx)| x + dx).collect();
+    let x = to_vector(&x);
+
+    let mut f = Tnsr::<f64>::new_vector(n);
+    system.eval(&x, &mut f)?;
+
+    point.x = x;
+    point.f = f;
+    point.dx = to_vector(&dx);
+
+    state.jacobian = Some(j);
+
+    Ok(state)
+}
+
+/// Create new Newton root finder
+pub fn new_newton_finder<E>() -> MultiRootFinder<NewtonState, E> {
+    MultiRootFinder::<NewtonState, E> {
+        state: NewtonState::new(),
+        solver: newton_iterate,
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_newton() {
+    use super::MultiConvergence;
+
+    // x^2 + y^2 = 4, x = y
+    let f = |x: &Tnsr<f64>, f: &mut Tnsr<f64>| -> Result<(), ()> {
+        f.v[0] = x.v[0]*x.v[0] + x.v[1]*x.v[1] - 4.0;
+        f.v[1] = x.v[0] - x.v[1];
+        Ok(())
+    };
+    let df = |x: &Tnsr<f64>, j: &mut Tnsr<f64>| -> Result<(), ()> {
+        j.set(0, 0, 2.0*x.v[0]).set(0, 1, 2.0*x.v[1]);
+        j.set(1, 0, 1.0).set(1, 1, -1.0);
+        Ok(())
+    };
+
+    let mut x0 = Tnsr::<f64>::new_vector(2);
+    x0.v = vec![1.0, 0.5];
+
+    let finder = new_newton_finder();
+    let criteria = [MultiConvergence::Delta{epsabs: 1.0e-15, epsrel: 0.0}];
+
+    let res = finder.find(MultiFn::with_jacobian(f, df), &x0, &criteria, 50)
+        .expect("root finder failed");
+    assert!(res.nr_iterations < 10);
+    assert_f64_near!(res.x.v[0], std::f64::consts::SQRT_2, 4);
+    assert_f64_near!(res.x.v[1], std::f64::consts::SQRT_2, 4);
+
+    let res = finder.find(MultiFn::new(f), &x0, &criteria, 50)
+        .expect("root finder failed");
+    assert!(res.nr_iterations < 10);
+    assert_f64_near!(res.x.v[0], std::f64::consts::SQRT_2, 4);
+}
@@ -0,0 +1,244 @@
+//! Step by step multidimensional root finding iterations and tracing.
+//!
+//! (c) Igor Lesik 2023
+//! MIT license
+//!
+//! References:
+//!
+//! - <https://www.gnu.org/software/gsl/doc/html/multiroots.html#iteration>
+
+use crate::la::tnsr::Tnsr;
+use super::{RootsErr, RootFinderState, MultiFn, MultiPoint, MultiSolver, MultiRootFinder,
+    MultiRootResult, MultiConvergence, convergence, multi_init};
+
+/// Record of one multidimensional root finding iteration
+#[derive(Clone, Debug)]
+pub struct MultiStep {
+    /// Iteration number starting from 1
+    pub iteration: usize,
+    /// Root estimate
+    pub x: Tnsr<f64>,
+    /// Function value at the root estimate
+    pub f: Tnsr<f64>,
+    /// Step that was made on the iteration
+    pub dx: Tnsr<f64>,
+}
+
+/// Multidimensional root finder driven by the caller one iteration at a time,
+/// like GSL `gsl_multiroot_fsolver_iterate`
+///
+/// # Example
+///
+/// ```
+/// # use rustamath::la::tnsr::Tnsr;
+/// # use rustamath::roots::multi::{MultiFn, newton::new_newton_finder};
+/// let system = MultiFn::new(|x: &Tnsr<f64>, f: &mut Tnsr<f64>| {
+///     f.v[0] = x.v[0]*x.v[0] - 2.0;
+///     Ok::<(), ()>(())
+/// });
+/// let mut x0 = Tnsr::<f64>::new_vector(1);
+/// x0.v[0] = 1.0;
+/// let mut stepper = new_newton_finder().stepper(system, &x0).unwrap();
+/// while stepper.f().v[0].abs() > 1.0e-12 {
+///     stepper.iterate().unwrap();
+/// }
+/// assert!((stepper.x().v[0] - 2.0f64.sqrt()).abs() < 1.0e-12);
+/// ```
+pub struct MultiStepper<'a, S, E> {
+    system: MultiFn<'a, E>,
+    solver: MultiSolver<S, E>,
+    state: S,
+    initial: S,
+    point: MultiPoint,
+    nr_iterations: usize,
+    failed: bool,
+}
+
+impl<'a, S, E> MultiStepper<'a, S, E>
+where
+    S: RootFinderState
+{
+    /// Perform one iteration
+    ///
+    /// If the function or solver fails, solver state is reset to the finder state and
+    /// next iteration starts again from the last point.
+    pub fn iterate(&mut self) -> Result<MultiStep, RootsErr<E>> {
+        self.advance()?;
+
+        Ok(MultiStep {
+            iteration: self.nr_iterations,
+            x: self.point.x.clone(),
+            f: self.point.f.clone(),
+            dx: self.point.dx.clone(),
+        })
+    }
+
+    /// Perform one iteration without recording it
+    fn advance(&mut self) -> Result<(), RootsErr<E>> {
+        let state = std::mem::replace(&mut self.state, self.initial.clone());
+
+        self.state = (self.solver)(&mut self.system, &mut self.point, state)?;
+        self.nr_iterations += 1;
+
+        Ok(())
+    }
+
+    /// Current root estimate
+    pub fn x(&self) -> &Tnsr<f64> {
+        &self.point.x
+    }
+
+    /// Function value at current root estimate
+    pub fn f(&self) -> &Tnsr<f64> {
+        &self.point.f
+    }
+
+    /// Last step
+    pub fn dx(&self) -> &Tnsr<f64> {
+        &self.point.dx
+    }
+
+    /// Current point
+    pub fn point(&self) -> &MultiPoint {
+        &self.point
+    }
+
+    /// Number of performed iterations
+    pub fn nr_iterations(&self) -> usize {
+        self.nr_iterations
+    }
+
+    /// Solver state
+    pub fn state(&self) -> &S {
+        &self.state
+    }
+}
+
+/// Infinite sequence of iterations, stops after first error
+impl<'a, S, E> Iterator for MultiStepper<'a, S, E>
+where
+    S: RootFinderState
+{
+    type Item = Result<MultiStep, RootsErr<E>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+        let step = self.iterate();
+        self.failed = step.is_err();
+        Some(step)
+    }
+}
+
+impl<S: RootFinderState, E> MultiRootFinder<S, E> {
+
+    /// Create stepper to drive iterations manually
+    pub fn stepper<'a>(
+        &self,
+        mut system: MultiFn<'a, E>,
+        x: &Tnsr<f64>,
+    ) -> Result<MultiStepper<'a, S, E>, RootsErr<E>>
+    {
+        let point = multi_init(&mut system, x)?;
+
+        Ok(MultiStepper {
+            system,
+            solver: self.solver,
+            state: self.state.clone(),
+            initial: self.state.clone(),
+            point,
+            nr_iterations: 0,
+            failed: false,
+        })
+    }
+
+    /// Call solvers till any of convergence criteria is met,
+    /// call observer after each iteration
+    pub fn find_observed<O>(
+        &self,
+        system: MultiFn<'_, E>,
+        x: &Tnsr<f64>,
+        criteria: &[MultiConvergence],
+        max_iterations: usize,
+        mut observer: O,
+    ) -> Result<MultiRootResult, RootsErr<E>>
+    where
+        O: FnMut(&MultiStep)
+    {
+        let mut stepper = self.stepper(system, x)?;
+        run(&mut stepper, criteria, max_iterations, Some(&mut observer))
+    }
+
+    /// Call solvers till any of convergence criteria is met,
+    /// return result and history of iterations
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustamath::la::tnsr::Tnsr;
+    /// # use rustamath::roots::multi::{MultiFn, MultiConvergence, broyden::new_broyden_finder};
+    /// let system = MultiFn::new(|x: &Tnsr<f64>, f: &mut Tnsr<f64>| {
+    ///     f.v[0] = x.v[0] + x.v[1] - 3.0;
+    ///     f.v[1] = x.v[0]*x.v[1] - 2.0;
+    ///     Ok::<(), ()>(())
+    /// });
+    /// let mut x0 = Tnsr::<f64>::new_vector(2);
+    /// x0.v = vec![0.5, 2.5];
+    /// let (res, history) = new_broyden_finder().find_traced(system, &x0,
+    ///     &[MultiConvergence::Delta{epsabs: 1.0e-12, epsrel: 1.0e-12}], 100).unwrap();
+    /// assert_eq!(history.len(), res.nr_iterations + 1);
+    /// assert!((res.x.v[0] - 1.0).abs() < 1.0e-10 && (res.x.v[1] - 2.0).abs() < 1.0e-10);
+    /// ```
+    pub fn find_traced(
+        &self,
+        system: MultiFn<'_, E>,
+        x: &Tnsr<f64>,
+        criteria: &[MultiConvergence],
+        max_iterations: usize,
+    ) -> Result<(MultiRootResult, Vec<MultiStep>), RootsErr<E>>
+    {
+        let mut history = Vec::new();
+        let res = self.find_observed(system, x, criteria, max_iterations,
+            |step| history.push(step.clone()))?;
+        Ok((res, history))
+    }
+}
+
+/// Iterate till any of convergence criteria is met or `max_iterations` is exhausted
+pub(super) fn run<S, E>(
+    stepper: &mut MultiStepper<'_, S, E>,
+    criteria: &[MultiConvergence],
+    max_iterations: usize,
+    mut observer: Option<&mut dyn FnMut(&MultiStep)>,
+) -> Result<MultiRootResult, RootsErr<E>>
+where
+    S: RootFinderState
+{
+    if !criteria.iter().all(MultiConvergence::is_valid) {
+        return Err(RootsErr::BadTolerance);
+    }
+
+    let mut nr_iterations: usize = 0;
+    let mut converged_by = None;
+
+    while nr_iterations < max_iterations {
+        match observer.as_mut() {
+            Some(observer) => observer(&stepper.iterate()?),
+            None => stepper.advance()?,
+        }
+        converged_by = convergence::first_met(criteria, stepper.point());
+        if converged_by.is_some() {
+            break;
+        }
+        nr_iterations += 1;
+    }
+
+    Ok(MultiRootResult {
+        x: stepper.x().clone(),
+        f: stepper.f().clone(),
+        nr_iterations,
+        converged_by,
+        max_iterations_reached: converged_by.is_none(),
+    })
+}
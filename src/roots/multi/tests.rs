@@ -0,0 +1,176 @@
+use super::*;
+use super::newton::new_newton_finder;
+use super::broyden::new_broyden_finder;
+use super::hybrid::new_hybrid_finder;
+
+type TestFn = fn(&Tnsr<f64>, &mut Tnsr<f64>) -> Result<(), ()>;
+
+fn rosenbrock(x: &Tnsr<f64>, f: &mut Tnsr<f64>) -> Result<(), ()> {
+    f.v[0] = 1.0 - x.v[0];
+    f.v[1] = 10.0 * (x.v[1] - x.v[0]*x.v[0]);
+    Ok(())
+}
+
+fn rosenbrock_df(x: &Tnsr<f64>, j: &mut Tnsr<f64>) -> Result<(), ()> {
+    j.set(0, 0, -1.0).set(0, 1, 0.0);
+    j.set(1, 0, -20.0*x.v[0]).set(1, 1, 10.0);
+    Ok(())
+}
+
+fn powell_badly_scaled(x: &Tnsr<f64>, f: &mut Tnsr<f64>) -> Result<(), ()> {
+    f.v[0] = 10000.0 * x.v[0] * x.v[1] - 1.0;
+    f.v[1] = (-x.v[0]).exp() + (-x.v[1]).exp() - 1.0001;
+    Ok(())
+}
+
+fn powell_badly_scaled_df(x: &Tnsr<f64>, j: &mut Tnsr<f64>) -> Result<(), ()> {
+    j.set(0, 0, 10000.0 * x.v[1]).set(0, 1, 10000.0 * x.v[0]);
+    j.set(1, 0, -(-x.v[0]).exp()).set(1, 1, -(-x.v[1]).exp());
+    Ok(())
+}
+
+// x + y + z = 6, x*y*z = 6, x^2 + y^2 - z^2 = -4, root (1, 2, 3)
+fn trio(x: &Tnsr<f64>, f: &mut Tnsr<f64>) -> Result<(), ()> {
+    f.v[0] = x.v[0] + x.v[1] + x.v[2] - 6.0;
+    f.v[1] = x.v[0] * x.v[1] * x.v[2] - 6.0;
+    f.v[2] = x.v[0]*x.v[0] + x.v[1]*x.v[1] - x.v[2]*x.v[2] + 4.0;
+    Ok(())
+}
+
+fn trio_df(x: &Tnsr<f64>, j: &mut Tnsr<f64>) -> Result<(), ()> {
+    j.set(0, 0, 1.0).set(0, 1, 1.0).set(0, 2, 1.0);
+    j.set(1, 0, x.v[1]*x.v[2]).set(1, 1, x.v[0]*x.v[2]).set(1, 2, x.v[0]*x.v[1]);
+    j.set(2, 0, 2.0*x.v[0]).set(2, 1, 2.0*x.v[1]).set(2, 2, -2.0*x.v[2]);
+    Ok(())
+}
+
+fn vector(v: &[f64]) -> Tnsr<f64> {
+    to_vector(v)
+}
+
+fn print_res(name: &str, msg: &str, res: &MultiRootResult) {
+    println!("{:<10} {:<20} iterations={:<3} x={:?} f={:?} converged_by={:?}",
+        name, msg, res.nr_iterations, res.x.v, res.f.v, res.converged_by);
+}
+
+#[allow(clippy::too_many_arguments)]
+fn test_finder<S: RootFinderState>(
+    name: &str,
+    finder: &MultiRootFinder<S>,
+    msg: &str,
+    f: TestFn,
+    df: Option<TestFn>,
+    x0: &[f64],
+    max_iters: usize,
+    expect: &[f64],
+    eps: f64,
+) {
+    let system = match df {
+        Some(df) => MultiFn::with_jacobian(f, df),
+        None => MultiFn::new(f),
+    };
+
+    let res = finder.find(system, &vector(x0),
+        &[MultiConvergence::Residual{epsabs: 1.0e-12}], max_iters)
+        .expect("root finder failed");
+
+    print_res(name, msg, &res);
+
+    assert!(!res.max_iterations_reached);
+    for (x, expect) in res.x.v.iter().zip(expect) {
+        assert_float_absolute_eq!(*x, *expect, eps);
+    }
+}
+
+fn test_solvers(
+    msg: &str,
+    f: TestFn,
+    df: TestFn,
+    x0: &[f64],
+    max_iters: usize,
+    expect: &[f64],
+    eps: f64,
+) {
+    test_finder("Newton", &new_newton_finder(), msg, f, Some(df), x0, max_iters, expect, eps);
+    test_finder("dNewton", &new_newton_finder(), msg, f, None, x0, max_iters, expect, eps);
+    test_finder("Broyden", &new_broyden_finder(), msg, f, None, x0, max_iters, expect, eps);
+    test_finder("Hybridj", &new_hybrid_finder(), msg, f, Some(df), x0, max_iters, expect, eps);
+    test_finder("Hybrid", &new_hybrid_finder(), msg, f, None, x0, max_iters, expect, eps);
+}
+
+#[test]
+fn multi_solvers() {
+    test_solvers("Rosenbrock", rosenbrock, rosenbrock_df, &[-1.2, 1.0], 100, &[1.0, 1.0], 1.0e-10);
+    test_solvers("x+y+z=6, xyz=6", trio, trio_df, &[0.8, 2.3, 2.8], 100, &[1.0, 2.0, 3.0], 1.0e-10);
+
+    // far away start needs trust region
+    test_finder("Hybridj", &new_hybrid_finder(), "Rosenbrock", rosenbrock, Some(rosenbrock_df),
+        &[-10.0, -5.0], 100, &[1.0, 1.0], 1.0e-10);
+    test_finder("Hybridj", &new_hybrid_finder(), "Powell badly scaled",
+        powell_badly_scaled, Some(powell_badly_scaled_df),
+        &[0.0, 1.0], 1000, &[1.098159e-5, 9.106146], 1.0e-5);
+}
+
+#[test]
+fn multi_tracing() {
+    let mut history = Vec::new();
+    let res = new_hybrid_finder().find_observed(MultiFn::with_jacobian(trio, trio_df),
+        &vector(&[0.8, 2.3, 2.8]), &[MultiConvergence::Delta{epsabs: 1.0e-14, epsrel: 0.0}], 100,
+        |step| history.push(step.clone()))
+        .expect("root finder failed");
+
+    assert_eq!(history.len(), res.nr_iterations + 1);
+    for (i, step) in history.iter().enumerate() {
+        assert_eq!(step.iteration, i + 1);
+        let mut f = Tnsr::<f64>::new_vector(3);
+        trio(&step.x, &mut f).unwrap();
+        assert_eq!(step.f.v, f.v);
+    }
+    assert_eq!(history.last().unwrap().x.v, res.x.v);
+
+    let mut stepper = new_newton_finder().stepper(MultiFn::new(rosenbrock), &vector(&[-1.2, 1.0]))
+        .expect("root finder failed");
+    assert_eq!(stepper.nr_iterations(), 0);
+    let step = stepper.iterate().expect("iteration failed");
+    assert_eq!(step.iteration, 1);
+    assert_eq!(&step.x.v, &stepper.x().v);
+    let last = stepper.take(10).last().unwrap().expect("iteration failed");
+    assert_eq!(last.iteration, 11);
+    assert!(last.f.v.iter().all(|f| f.abs() < 1.0e-12));
+}
+
+#[test]
+fn multi_errors() {
+    let criteria = [MultiConvergence::Residual{epsabs: 1.0e-12}];
+
+    // user error is passed to the caller
+    let mut nr_calls = 0;
+    let res = new_broyden_finder().find(MultiFn::new(|x: &Tnsr<f64>, f: &mut Tnsr<f64>| {
+        nr_calls += 1;
+        if nr_calls > 3 { return Err(nr_calls); }
+        f.v[0] = x.v[0] - 1.0;
+        f.v[1] = x.v[1] * x.v[1] - 2.0;
+        Ok(())
+    }), &vector(&[3.0, 3.0]), &criteria, 100);
+    assert!(matches!(res, Err(RootsErr::FunctionFailed(4))));
+
+    // Jacobian is singular at the start
+    let res = new_newton_finder().find(MultiFn::with_jacobian(|x: &Tnsr<f64>, f: &mut Tnsr<f64>| {
+        f.v[0] = x.v[0] * x.v[0] - 1.0;
+        f.v[1] = x.v[1] - 1.0;
+        Ok::<(), ()>(())
+    }, |x: &Tnsr<f64>, j: &mut Tnsr<f64>| {
+        j.set(0, 0, 2.0 * x.v[0]).set(0, 1, 0.0);
+        j.set(1, 0, 0.0).set(1, 1, 1.0);
+        Ok(())
+    }), &vector(&[0.0, 0.0]), &criteria, 100);
+    assert!(matches!(res, Err(RootsErr::SingularJacobian)));
+
+    let res = new_newton_finder().find(MultiFn::new(rosenbrock), &Tnsr::<f64>::new_vector(0),
+        &criteria, 100);
+    assert!(matches!(res, Err(RootsErr::DimensionsMismatch)));
+
+    let res = new_newton_finder().find(MultiFn::new(rosenbrock), &vector(&[0.0, 0.0]),
+        &[MultiConvergence::Residual{epsabs: -1.0}], 100);
+    assert!(matches!(res, Err(RootsErr::BadTolerance)));
+}
@@ -0,0 +1,283 @@
+//! Newton-Raphson root finding algorithms that use derivative.
+//!
+//! (c) Igor Lesik 2023
+//! MIT license
+//!
+//! References:
+//!
+//! - <https://en.wikipedia.org/wiki/Newton%27s_method>
+//! - <https://github.com/ampl/gsl/blob/master/roots/newton.c>
+//! - William H. Press - Numerical recipes, `rtsafe`
+
+use num_traits::float::Float;
+use super::{RootsErr, Range, RootFinderState, RootFinderResult, Convergence, stepper,
+    cast, is_same_sign};
+
+/// Function with roots that returns `(f(x), df(x)/dx)` in one call,
+/// useful when `f` and `df` share calculations
+pub type FnWithDerivative<'a, E, T = f64> = dyn FnMut(T) -> Result<(T, T), E> + 'a;
+
+/// Combine function `f(x)` and its derivative `df(x)/dx` into one function
+/// that returns `(f(x), df(x)/dx)`
+///
+/// # Example
+///
+/// ```
+/// # use rustamath::roots::newton::*;
+/// # use assert_float_eq::*;
+/// let fdf = fn_and_derivative(|x: f64| Ok::<f64, ()>(x.sin()), |x: f64| Ok(x.cos()));
+/// let res = new_newton_finder().find(fdf, 3.0, 3.0, 1.0e-15, 50);
+/// assert_f64_near!(res.unwrap().root, std::f64::consts::PI, 4);
+/// ```
+pub fn fn_and_derivative<E, T, F, DF>(mut f: F, mut df: DF) -> impl FnMut(T) -> Result<(T, T), E>
+where
+    T: Copy,
+    F: FnMut(T) -> Result<T, E>,
+    DF: FnMut(T) -> Result<T, E>,
+{
+    move |x| Ok((f(x)?, df(x)?))
+}
+
+/// Prepare derivative solver, return initial root and range
+pub type DerivInit<E, T = f64> = fn(
+    &mut FnWithDerivative<'_, E, T>,
+    T,
+    T,
+) -> Result<(T, Range<T>), RootsErr<E>>;
+
+/// Derivative solver function that performs one root finding iteration
+/// starting from current root estimate
+pub type DerivSolver<S, E, T = f64> = fn(
+    &mut FnWithDerivative<'_, E, T>,
+    T,
+    Range<T>,
+    S,
+) -> Result<(T, Range<T>, S), RootsErr<E>>;
+
+/// Root finder that uses function derivative
+pub struct DerivRootFinder<S, E = (), T = f64> {
+    /// State that gets transferred  between iterations
+    pub state: S,
+    pub(super) init: DerivInit<E, T>,
+    pub(super) solver: DerivSolver<S, E, T>,
+}
+
+impl<S: RootFinderState, E, T: Float> DerivRootFinder<S, E, T> {
+
+    /// Call solvers till `abs(prev-next) < epsilon`
+    ///
+    /// Initial root guess is in the middle of `[x_left, x_right]`,
+    /// for unbracketed Newton pass `x_left == x_right == guess`.
+    pub fn find<F>(
+        &self,
+        fun: F,
+        x_left: T,
+        x_right: T,
+        epsilon: T,
+        max_iterations: usize
+    ) -> Result<RootFinderResult<T>, RootsErr<E>>
+    where
+        F: FnMut(T) -> Result<(T, T), E>
+    {
+        self.find_with(fun, x_left, x_right, &[S::find_criterion(epsilon)], max_iterations)
+    }
+
+    /// Call solvers till any of convergence criteria is met
+    pub fn find_with<F>(
+        &self,
+        fun: F,
+        x_left: T,
+        x_right: T,
+        criteria: &[Convergence<T>],
+        max_iterations: usize
+    ) -> Result<RootFinderResult<T>, RootsErr<E>>
+    where
+        F: FnMut(T) -> Result<(T, T), E>
+    {
+        let mut stepper = self.stepper(fun, x_left, x_right)?;
+        stepper::run(&mut stepper, criteria, max_iterations, None)
+    }
+}
+
+/// Newton state that is transferred between iterations
+#[derive(Clone)]
+pub struct NewtonState<T = f64> {
+    /// Function value at last evaluated root
+    pub f: T,
+    /// Derivative value at last evaluated root
+    pub df: T,
+}
+
+impl<T: Float> RootFinderState for NewtonState<T> {
+    fn new() -> NewtonState<T> { NewtonState{f: T::nan(), df: T::nan()} }
+}
+
+/// Newton initial guess is in the middle of the range, range is not checked
+pub fn newton_init<E, T: Float>(
+    _f: &mut FnWithDerivative<'_, E, T>,
+    x_left: T,
+    x_right: T) -> Result<(T, Range<T>), RootsErr<E>>
+{
+    Ok(((x_left + x_right)/cast(2.0), (x_left, x_right, T::nan(), T::nan())))
+}
+
+/// Newton step `x_next = x - f(x)/f'(x)`
+///
+/// Input range is not used, returned range holds two last iterates,
+/// function value at `x_next` is not known and set to NaN.
+pub fn newton_iterate<E, T: Float>(
+    f: &mut FnWithDerivative<'_, E, T>,
+    root: T,
+    _range: Range<T>,
+    _state: NewtonState<T>,
+    ) -> Result<(T, Range<T>, NewtonState<T>), RootsErr<E>>
+{
+    let (y, dy) = f(root).map_err(RootsErr::FunctionFailed)?;
+
+    if y == T::zero() {
+        return Ok((root, (root, root, y, y), NewtonState{f: y, df: dy}));
+    }
+
+    if dy == T::zero() {
+        return Err(RootsErr::ZeroDerivative);
+    }
+
+    let root_next = root - y/dy;
+
+    if !root_next.is_finite() {
+        return Err(RootsErr::ZeroDerivative);
+    }
+
+    let range = if root < root_next {
+        (root, root_next, y, T::nan())
+    } else {
+        (root_next, root, T::nan(), y)
+    };
+
+    Ok((root_next, range, NewtonState{f: y, df: dy}))
+}
+
+/// Create new Newton root finder
+pub fn new_newton_finder<E, T: Float>() -> DerivRootFinder<NewtonState<T>, E, T> {
+    DerivRootFinder::<NewtonState<T>, E, T> {
+        state: NewtonState::new(),
+        init: newton_init,
+        solver: newton_iterate,
+    }
+}
+
+/// Safeguarded Newton state that is transferred between iterations
+#[derive(Clone)]
+pub struct SafeNewtonState<T = f64> {
+    /// Step before last step
+    pub dx_old: T,
+    /// Last step
+    pub dx: T,
+}
+
+impl<T: Float> RootFinderState for SafeNewtonState<T> {
+    fn new() -> SafeNewtonState<T> {
+        SafeNewtonState{dx_old: T::infinity(), dx: T::infinity()}
+    }
+}
+
+/// Safeguarded Newton starts in the middle of the range that must straddle `y=0`
+pub fn safe_newton_init<E, T: Float>(
+    f: &mut FnWithDerivative<'_, E, T>,
+    x_left: T,
+    x_right: T) -> Result<(T, Range<T>), RootsErr<E>>
+{
+    let (f_left, _) = f(x_left).map_err(RootsErr::FunctionFailed)?;
+    let (f_right, _) = f(x_right).map_err(RootsErr::FunctionFailed)?;
+
+    if is_same_sign(f_left, f_right) {
+        return Err(RootsErr::EndpointsNotStraddleYeq0);
+    }
+
+    Ok(((x_left + x_right)/cast(2.0), (x_left, x_right, f_left, f_right)))
+}
+
+/// Newton step that falls back to bisection when the step leaves the bracket
+/// or does not reduce the bracket fast enough.
+///
+/// Range `(x_left, x_right, f_left, f_right)` always brackets the root.
+pub fn safe_newton_iterate<E, T: Float>(
+    f: &mut FnWithDerivative<'_, E, T>,
+    root: T,
+    (x_left, x_right, f_left, f_right): Range<T>,
+    state: SafeNewtonState<T>,
+    ) -> Result<(T, Range<T>, SafeNewtonState<T>), RootsErr<E>>
+{
+    let zero = T::zero();
+    let two: T = cast(2.0);
+
+    if f_left == zero {
+        return Ok((x_left, (x_left, x_left, f_left, f_left), state));
+    }
+
+    if f_right == zero {
+        return Ok((x_right, (x_right, x_right, f_right, f_right), state));
+    }
+
+    let (y, dy) = f(root).map_err(RootsErr::FunctionFailed)?;
+
+    if y == zero {
+        return Ok((root, (root, root, y, y), state));
+    }
+
+    // Shrink the bracket with the value at current root.
+    let (x_left, x_right, f_left, f_right) =
+        if y.is_sign_positive() == f_left.is_sign_positive() {
+            (root, x_right, y, f_right)
+        } else {
+            (x_left, root, f_left, y)
+        };
+
+    // Bisect if Newton is out of range or not decreasing fast enough,
+    // see NR `rtsafe`.
+    let newton_out_of_range = ((root - x_right)*dy - y) * ((root - x_left)*dy - y) > zero;
+    let newton_slow = (two*y).abs() > (state.dx_old*dy).abs();
+
+    let dx_old = state.dx;
+    let (root_next, dx) = if dy == zero || newton_out_of_range || newton_slow {
+        let x_bisect = (x_left + x_right) / two;
+        (x_bisect, x_bisect - root)
+    } else {
+        let dx = -y/dy;
+        (root + dx, dx)
+    };
+
+    Ok((root_next, (x_left, x_right, f_left, f_right), SafeNewtonState{dx_old, dx}))
+}
+
+/// Create new safeguarded Newton root finder
+pub fn new_safe_newton_finder<E, T: Float>() -> DerivRootFinder<SafeNewtonState<T>, E, T> {
+    DerivRootFinder::<SafeNewtonState<T>, E, T> {
+        state: SafeNewtonState::new(),
+        init: safe_newton_init,
+        solver: safe_newton_iterate,
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_newton() {
+    use crate::polynomial::derivative_polynomial_n;
+
+    // (x - 1)(x - 2)(x + 3) = x^3 - 7x + 6
+    let coeffs = vec![6.0, -7.0, 0.0, 1.0];
+    let poly = |x| -> Result<(f64, f64), ()> {
+        let (_x, p, dp) = derivative_polynomial_n(x, &coeffs);
+        Ok((p, dp))
+    };
+
+    let res = new_newton_finder().find(poly, 2.4, 2.4, 1.0e-15, 50)
+        .expect("root finder failed");
+    assert!(res.nr_iterations < 50);
+    assert_f64_near!(res.root, 2.0, 4);
+
+    let res = new_safe_newton_finder().find(poly, -10.0, 0.0, 1.0e-15, 50)
+        .expect("root finder failed");
+    assert!(res.nr_iterations < 50);
+    assert_f64_near!(res.root, -3.0, 4);
+}
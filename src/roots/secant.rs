@@ -0,0 +1,108 @@
+//! Secant root finding algorithm.
+//!
+//! (c) Igor Lesik 2023
+//! MIT license
+//!
+//! References:
+//!
+//! - <https://en.wikipedia.org/wiki/Secant_method>
+//! - <https://github.com/ampl/gsl/blob/master/roots/secant.c>
+
+use num_traits::float::Float;
+use super::{RootsErr, Range, FnWithRoots, RootFinder, RootFinderState, open_init};
+
+/// Secant state that is transferred between iteration,
+/// it keeps two last iterates
+#[derive(Clone)]
+pub struct SecantState<T = f64> {
+    x_prev: T,
+    f_prev: T,
+    x: T,
+    f: T,
+    started: bool,
+}
+
+impl<T: Float> RootFinderState for SecantState<T> {
+    fn new() -> SecantState<T> {
+        SecantState{x_prev: T::nan(), f_prev: T::nan(), x: T::nan(), f: T::nan(), started: false}
+    }
+}
+
+/// Secant step `x_next = x - f(x)*(x - x_prev)/(f(x) - f(x_prev))`
+///
+/// Iterations start from the range endpoints that do not need to bracket the root,
+/// after that the range is not kept as a bracket, returned range holds two last iterates.
+/// Equal function values at two last iterates give `RootsErr::ZeroDerivative`.
+pub fn secant_iterate<E, T: Float>(
+    f: &mut FnWithRoots<'_, E, T>,
+    (x_left, x_right, f_left, f_right): Range<T>,
+    state: SecantState<T>,
+    ) -> Result<(T, Range<T>, SecantState<T>), RootsErr<E>>
+{
+    let SecantState {x_prev, f_prev, x, f: fx, ..} = if state.started {
+        state
+    }
+    else {
+        SecantState{x_prev: x_left, f_prev: f_left, x: x_right, f: f_right, started: true}
+    };
+
+    if fx == T::zero() {
+        return Ok((x, (x, x, fx, fx), SecantState{x_prev, f_prev, x, f: fx, started: true}));
+    }
+
+    // flat secant, no progress is possible
+    if fx == f_prev {
+        return Err(RootsErr::ZeroDerivative);
+    }
+
+    let x_next = x - fx * (x - x_prev) / (fx - f_prev);
+
+    if !x_next.is_finite() {
+        return Err(RootsErr::ZeroDerivative);
+    }
+
+    let f_next = match f(x_next) {
+        Ok(y) => y,
+        Err(err) => return Err(RootsErr::FunctionFailed(err)),
+    };
+
+    let range = if x < x_next { (x, x_next, fx, f_next) } else { (x_next, x, f_next, fx) };
+
+    Ok((x_next, range, SecantState{x_prev: x, f_prev: fx, x: x_next, f: f_next, started: true}))
+}
+
+/// Create new secant root finder
+pub fn new_secant_finder<E, T: Float>() -> RootFinder<SecantState<T>, E, T> {
+    RootFinder::<SecantState<T>, E, T> {
+        state: SecantState::new(),
+        init: open_init,
+        solver: secant_iterate,
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_secant() {
+    #[inline] fn sin(x: f64) -> Result<f64, ()> {
+        Ok(x.sin())
+    }
+
+    let finder = new_secant_finder();
+
+    let res = finder.find(sin, 3.0, 4.0, 1.0e-15, 50)
+        .expect("root finder failed");
+
+    assert!(res.nr_iterations < 50);
+    assert_f64_near!(res.root, std::f64::consts::PI, 4);
+
+    // starting points on one side of the root
+    let res = finder.find(|x| Ok(x * x - 2.0), 2.0, 3.0, 1.0e-15, 50)
+        .expect("root finder failed");
+    assert_f64_near!(res.root, 2.0f64.sqrt(), 4);
+
+    // plateau
+    let res = finder.find(|_| Ok(1.0), 0.0, 1.0, 1.0e-15, 50);
+    assert!(matches!(res, Err(RootsErr::ZeroDerivative)));
+    let res = finder.find(|x: f64| Ok(x.max(1.0)), 2.0, 3.0, 1.0e-15, 50);
+    assert!(matches!(res, Err(RootsErr::ZeroDerivative)));
+}
@@ -0,0 +1,119 @@
+//! Steffensen root finding algorithm.
+//!
+//! (c) Igor Lesik 2023
+//! MIT license
+//!
+//! References:
+//!
+//! - <https://en.wikipedia.org/wiki/Steffensen%27s_method>
+//! - <https://github.com/ampl/gsl/blob/master/roots/steffenson.c>
+
+use num_traits::float::Float;
+use super::{RootsErr, Range, FnWithRoots, RootFinder, RootFinderState, cast, open_init};
+
+/// Steffensen state that is transferred between iteration,
+/// it keeps last iterate and function value at it
+#[derive(Clone)]
+pub struct SteffensenState<T = f64> {
+    x: T,
+    f: T,
+    started: bool,
+}
+
+impl<T: Float> RootFinderState for SteffensenState<T> {
+    fn new() -> SteffensenState<T> {
+        SteffensenState{x: T::nan(), f: T::nan(), started: false}
+    }
+}
+
+/// Steffensen step `x_next = x - f(x)/g(x)` where `g(x) = (f(x + f(x)) - f(x))/f(x)`
+///
+/// Derivative free method with quadratic convergence,
+/// it needs good initial guess and a function scaled such that `|f(x)|` is small near the root.
+/// Iterations start from the middle of the range that does not need to bracket the root,
+/// after that the range is not kept as a bracket, returned range holds two last iterates.
+/// Zero slope estimate away from the root gives `RootsErr::ZeroDerivative`.
+pub fn steffensen_iterate<E, T: Float>(
+    f: &mut FnWithRoots<'_, E, T>,
+    (x_left, x_right, _f_left, _f_right): Range<T>,
+    state: SteffensenState<T>,
+    ) -> Result<(T, Range<T>, SteffensenState<T>), RootsErr<E>>
+{
+    let (x, fx) = if state.started {
+        (state.x, state.f)
+    }
+    else {
+        let x = (x_left + x_right) / cast(2.0);
+        match f(x) {
+            Ok(y) => (x, y),
+            Err(err) => return Err(RootsErr::FunctionFailed(err)),
+        }
+    };
+
+    if fx == T::zero() {
+        return Ok((x, (x, x, fx, fx), SteffensenState{x, f: fx, started: true}));
+    }
+
+    let f_shift = match f(x + fx) {
+        Ok(y) => y,
+        Err(err) => return Err(RootsErr::FunctionFailed(err)),
+    };
+
+    if f_shift == fx {
+        // Shift is below float spacing, `x` is as close to the root as we can get.
+        if x + fx == x {
+            return Ok((x, (x, x, fx, fx), SteffensenState{x, f: fx, started: true}));
+        }
+        // flat function, slope can not be estimated
+        return Err(RootsErr::ZeroDerivative);
+    }
+
+    let x_next = x - fx * fx / (f_shift - fx);
+
+    if !x_next.is_finite() {
+        return Err(RootsErr::ZeroDerivative);
+    }
+
+    let f_next = match f(x_next) {
+        Ok(y) => y,
+        Err(err) => return Err(RootsErr::FunctionFailed(err)),
+    };
+
+    let range = if x < x_next { (x, x_next, fx, f_next) } else { (x_next, x, f_next, fx) };
+
+    Ok((x_next, range, SteffensenState{x: x_next, f: f_next, started: true}))
+}
+
+/// Create new Steffensen root finder
+pub fn new_steffensen_finder<E, T: Float>() -> RootFinder<SteffensenState<T>, E, T> {
+    RootFinder::<SteffensenState<T>, E, T> {
+        state: SteffensenState::new(),
+        init: open_init,
+        solver: steffensen_iterate,
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_steffensen() {
+    #[inline] fn sin(x: f64) -> Result<f64, ()> {
+        Ok(x.sin())
+    }
+
+    let finder = new_steffensen_finder();
+
+    let res = finder.find(sin, 3.0, 4.0, 1.0e-15, 50)
+        .expect("root finder failed");
+
+    assert!(res.nr_iterations < 50);
+    assert_f64_near!(res.root, std::f64::consts::PI, 4);
+
+    // range on one side of the root
+    let res = finder.find(|x| Ok(x * x - 2.0), 1.5, 2.0, 1.0e-15, 50)
+        .expect("root finder failed");
+    assert_f64_near!(res.root, 2.0f64.sqrt(), 4);
+
+    // plateau
+    let res = finder.find(|_| Ok(1.0), 0.0, 1.0, 1.0e-15, 50);
+    assert!(matches!(res, Err(RootsErr::ZeroDerivative)));
+}
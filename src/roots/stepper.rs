@@ -0,0 +1,512 @@
+//! Step by step root finding iterations and tracing.
+//!
+//! (c) Igor Lesik 2023
+//! MIT license
+//!
+//! References:
+//!
+//! - <https://www.gnu.org/software/gsl/doc/html/roots.html#iteration>
+
+use num_traits::float::Float;
+use super::{RootsErr, Range, Solver, RootFinder, RootFinderState, RootFinderResult,
+    Convergence, convergence};
+use super::newton::{DerivSolver, DerivRootFinder};
+
+/// Record of one root finding iteration
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RootStep<T = f64> {
+    /// Iteration number starting from 1
+    pub iteration: usize,
+    /// Root estimate
+    pub root: T,
+    /// Function value at the root estimate, NaN if not evaluated
+    pub f_root: T,
+    /// Range after the iteration
+    pub range: Range<T>,
+}
+
+/// Root finding result with history of iterations
+pub type RootTrace<T = f64> = (RootFinderResult<T>, Vec<RootStep<T>>);
+
+/// Observer that is called after each iteration
+type Observer<'a, T> = dyn FnMut(&RootStep<T>) + 'a;
+
+/// Iterations one at a time, common interface of [`RootStepper`] and [`DerivRootStepper`]
+pub trait RootIterate<E, T = f64> {
+    /// Perform one iteration
+    fn iterate(&mut self) -> Result<RootStep<T>, RootsErr<E>>;
+
+    /// Evaluate function at each root estimate for [`RootStep::f_root`]
+    fn eval_root(&mut self, eval: bool) -> &mut Self;
+
+    /// Current root estimate
+    fn root(&self) -> T;
+
+    /// Current range
+    fn range(&self) -> Range<T>;
+
+    /// Evaluate function at a point
+    fn eval(&mut self, x: T) -> Result<T, RootsErr<E>>;
+}
+
+/// Root finder driven by the caller one iteration at a time,
+/// like GSL `gsl_root_fsolver_iterate`
+///
+/// # Example
+///
+/// ```
+/// # use rustamath::roots::itp::new_itp_finder;
+/// let finder = new_itp_finder();
+/// let mut stepper = finder.stepper(|x: f64| Ok::<f64, ()>(x.sin()), 3.0, 4.0).unwrap();
+/// while stepper.range().1 - stepper.range().0 > 1.0e-10 {
+///     stepper.iterate().unwrap();
+/// }
+/// assert!((stepper.root() - std::f64::consts::PI).abs() < 1.0e-10);
+///
+/// // or as an iterator
+/// let widths: Vec<f64> = finder.stepper(|x: f64| Ok::<f64, ()>(x.sin()), 3.0, 4.0).unwrap()
+///     .take(10)
+///     .map(|step| step.map(|s| s.range.1 - s.range.0).unwrap())
+///     .collect();
+/// assert!(widths.windows(2).all(|w| w[1] <= w[0]));
+/// ```
+pub struct RootStepper<S, E, F, T = f64> {
+    fun: F,
+    solver: Solver<S, E, T>,
+    state: S,
+    initial: S,
+    root: T,
+    range: Range<T>,
+    nr_iterations: usize,
+    eval_root: bool,
+    failed: bool,
+}
+
+impl<S, E, F, T> RootStepper<S, E, F, T>
+where
+    S: RootFinderState,
+    T: Float,
+    F: FnMut(T) -> Result<T, E>
+{
+    /// Perform one iteration
+    ///
+    /// If the function or solver fails, solver state is reset to the finder state and
+    /// next iteration starts again from the last range.
+    pub fn iterate(&mut self) -> Result<RootStep<T>, RootsErr<E>> {
+        let state = std::mem::replace(&mut self.state, self.initial.clone());
+
+        let (root, range, state) = (self.solver)(&mut self.fun, self.range, state)?;
+
+        let f_root = if self.eval_root {
+            (self.fun)(root).map_err(RootsErr::FunctionFailed)?
+        } else {
+            T::nan()
+        };
+
+        self.root = root;
+        self.range = range;
+        self.state = state;
+        self.nr_iterations += 1;
+
+        Ok(RootStep {
+            iteration: self.nr_iterations,
+            root,
+            f_root,
+            range,
+        })
+    }
+
+    /// Evaluate function at each root estimate for [`RootStep::f_root`], default is true
+    pub fn eval_root(&mut self, eval: bool) -> &mut Self {
+        self.eval_root = eval;
+        self
+    }
+
+    /// Current root estimate
+    pub fn root(&self) -> T {
+        self.root
+    }
+
+    /// Current range
+    pub fn range(&self) -> Range<T> {
+        self.range
+    }
+
+    /// Number of performed iterations
+    pub fn nr_iterations(&self) -> usize {
+        self.nr_iterations
+    }
+
+    /// Solver state
+    pub fn state(&self) -> &S {
+        &self.state
+    }
+
+    /// Evaluate function at a point
+    pub fn eval(&mut self, x: T) -> Result<T, RootsErr<E>> {
+        (self.fun)(x).map_err(RootsErr::FunctionFailed)
+    }
+}
+
+/// Infinite sequence of iterations, stops after first error
+impl<S, E, F, T> Iterator for RootStepper<S, E, F, T>
+where
+    S: RootFinderState,
+    T: Float,
+    F: FnMut(T) -> Result<T, E>
+{
+    type Item = Result<RootStep<T>, RootsErr<E>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+        let step = self.iterate();
+        self.failed = step.is_err();
+        Some(step)
+    }
+}
+
+impl<S, E, F, T> RootIterate<E, T> for RootStepper<S, E, F, T>
+where
+    S: RootFinderState,
+    T: Float,
+    F: FnMut(T) -> Result<T, E>
+{
+    fn iterate(&mut self) -> Result<RootStep<T>, RootsErr<E>> { RootStepper::iterate(self) }
+    fn eval_root(&mut self, eval: bool) -> &mut Self { RootStepper::eval_root(self, eval) }
+    fn root(&self) -> T { self.root }
+    fn range(&self) -> Range<T> { self.range }
+    fn eval(&mut self, x: T) -> Result<T, RootsErr<E>> { RootStepper::eval(self, x) }
+}
+
+/// Root finder with derivative driven by the caller one iteration at a time
+///
+/// # Example
+///
+/// ```
+/// # use rustamath::roots::newton::new_safe_newton_finder;
+/// let finder = new_safe_newton_finder();
+/// let mut stepper = finder.stepper(|x: f64| Ok::<(f64, f64), ()>((x.sin(), x.cos())), 3.0, 4.0)
+///     .unwrap();
+/// while stepper.range().1 - stepper.range().0 > 1.0e-10 {
+///     stepper.iterate().unwrap();
+/// }
+/// assert!((stepper.root() - std::f64::consts::PI).abs() < 1.0e-10);
+/// ```
+pub struct DerivRootStepper<S, E, F, T = f64> {
+    fun: F,
+    solver: DerivSolver<S, E, T>,
+    state: S,
+    initial: S,
+    root: T,
+    range: Range<T>,
+    nr_iterations: usize,
+    eval_root: bool,
+    failed: bool,
+}
+
+impl<S, E, F, T> DerivRootStepper<S, E, F, T>
+where
+    S: RootFinderState,
+    T: Float,
+    F: FnMut(T) -> Result<(T, T), E>
+{
+    /// Perform one iteration
+    ///
+    /// If the function or solver fails, solver state is reset to the finder state and
+    /// next iteration starts again from the last root estimate and range.
+    pub fn iterate(&mut self) -> Result<RootStep<T>, RootsErr<E>> {
+        let state = std::mem::replace(&mut self.state, self.initial.clone());
+
+        let (root, range, state) = (self.solver)(&mut self.fun, self.root, self.range, state)?;
+
+        let f_root = if self.eval_root {
+            (self.fun)(root).map_err(RootsErr::FunctionFailed)?.0
+        } else {
+            T::nan()
+        };
+
+        self.root = root;
+        self.range = range;
+        self.state = state;
+        self.nr_iterations += 1;
+
+        Ok(RootStep {
+            iteration: self.nr_iterations,
+            root,
+            f_root,
+            range,
+        })
+    }
+
+    /// Evaluate function at each root estimate for [`RootStep::f_root`], default is true
+    pub fn eval_root(&mut self, eval: bool) -> &mut Self {
+        self.eval_root = eval;
+        self
+    }
+
+    /// Current root estimate
+    pub fn root(&self) -> T {
+        self.root
+    }
+
+    /// Current range
+    pub fn range(&self) -> Range<T> {
+        self.range
+    }
+
+    /// Number of performed iterations
+    pub fn nr_iterations(&self) -> usize {
+        self.nr_iterations
+    }
+
+    /// Solver state
+    pub fn state(&self) -> &S {
+        &self.state
+    }
+
+    /// Evaluate function at a point
+    pub fn eval(&mut self, x: T) -> Result<T, RootsErr<E>> {
+        (self.fun)(x).map(|(y, _)| y).map_err(RootsErr::FunctionFailed)
+    }
+}
+
+/// Infinite sequence of iterations, stops after first error
+impl<S, E, F, T> Iterator for DerivRootStepper<S, E, F, T>
+where
+    S: RootFinderState,
+    T: Float,
+    F: FnMut(T) -> Result<(T, T), E>
+{
+    type Item = Result<RootStep<T>, RootsErr<E>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+        let step = self.iterate();
+        self.failed = step.is_err();
+        Some(step)
+    }
+}
+
+impl<S, E, F, T> RootIterate<E, T> for DerivRootStepper<S, E, F, T>
+where
+    S: RootFinderState,
+    T: Float,
+    F: FnMut(T) -> Result<(T, T), E>
+{
+    fn iterate(&mut self) -> Result<RootStep<T>, RootsErr<E>> { DerivRootStepper::iterate(self) }
+    fn eval_root(&mut self, eval: bool) -> &mut Self { DerivRootStepper::eval_root(self, eval) }
+    fn root(&self) -> T { self.root }
+    fn range(&self) -> Range<T> { self.range }
+    fn eval(&mut self, x: T) -> Result<T, RootsErr<E>> { DerivRootStepper::eval(self, x) }
+}
+
+impl<S: RootFinderState, E, T: Float> RootFinder<S, E, T> {
+
+    /// Create stepper to drive iterations manually
+    pub fn stepper<F>(
+        &self,
+        mut fun: F,
+        x_left: T,
+        x_right: T,
+    ) -> Result<RootStepper<S, E, F, T>, RootsErr<E>>
+    where
+        F: FnMut(T) -> Result<T, E>
+    {
+        let (root, range) = (self.init)(&mut fun, x_left, x_right)?;
+
+        Ok(RootStepper {
+            fun,
+            solver: self.solver,
+            state: self.state.clone(),
+            initial: self.state.clone(),
+            root,
+            range,
+            nr_iterations: 0,
+            eval_root: true,
+            failed: false,
+        })
+    }
+
+    /// Call solvers till any of convergence criteria is met,
+    /// call observer after each iteration
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustamath::roots::{Convergence, bisection::new_bisection_finder};
+    /// let mut history = Vec::new();
+    /// let res = new_bisection_finder().find_observed(|x: f64| Ok::<f64, ()>(x.cos()), 0.0, 3.0,
+    ///     &[Convergence::Interval{epsabs: 1.0e-6, epsrel: 0.0}], 100,
+    ///     |step| history.push((step.iteration, step.root, step.f_root, step.range)));
+    /// assert_eq!(history.len(), res.unwrap().nr_iterations + 1);
+    /// ```
+    pub fn find_observed<F, O>(
+        &self,
+        fun: F,
+        x_left: T,
+        x_right: T,
+        criteria: &[Convergence<T>],
+        max_iterations: usize,
+        mut observer: O,
+    ) -> Result<RootFinderResult<T>, RootsErr<E>>
+    where
+        F: FnMut(T) -> Result<T, E>,
+        O: FnMut(&RootStep<T>)
+    {
+        let mut stepper = self.stepper(fun, x_left, x_right)?;
+        run(&mut stepper, criteria, max_iterations, Some(&mut observer))
+    }
+
+    /// Call solvers till any of convergence criteria is met,
+    /// return result and history of iterations
+    pub fn find_traced<F>(
+        &self,
+        fun: F,
+        x_left: T,
+        x_right: T,
+        criteria: &[Convergence<T>],
+        max_iterations: usize,
+    ) -> Result<RootTrace<T>, RootsErr<E>>
+    where
+        F: FnMut(T) -> Result<T, E>
+    {
+        let mut history = Vec::with_capacity(max_iterations);
+        let res = self.find_observed(fun, x_left, x_right, criteria, max_iterations,
+            |step| history.push(*step))?;
+        Ok((res, history))
+    }
+}
+
+impl<S: RootFinderState, E, T: Float> DerivRootFinder<S, E, T> {
+
+    /// Create stepper to drive iterations manually
+    pub fn stepper<F>(
+        &self,
+        mut fun: F,
+        x_left: T,
+        x_right: T,
+    ) -> Result<DerivRootStepper<S, E, F, T>, RootsErr<E>>
+    where
+        F: FnMut(T) -> Result<(T, T), E>
+    {
+        let (root, range) = (self.init)(&mut fun, x_left, x_right)?;
+
+        Ok(DerivRootStepper {
+            fun,
+            solver: self.solver,
+            state: self.state.clone(),
+            initial: self.state.clone(),
+            root,
+            range,
+            nr_iterations: 0,
+            eval_root: true,
+            failed: false,
+        })
+    }
+
+    /// Call solvers till any of convergence criteria is met,
+    /// call observer after each iteration
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustamath::roots::{Convergence, newton::new_newton_finder};
+    /// let mut history = Vec::new();
+    /// let res = new_newton_finder().find_observed(|x: f64| Ok::<(f64, f64), ()>((x*x - 2.0, 2.0*x)),
+    ///     1.0, 1.0, &[Convergence::Residual{epsabs: 1.0e-12}], 100,
+    ///     |step| history.push((step.iteration, step.root, step.f_root)));
+    /// assert_eq!(history.len(), res.unwrap().nr_iterations + 1);
+    /// ```
+    pub fn find_observed<F, O>(
+        &self,
+        fun: F,
+        x_left: T,
+        x_right: T,
+        criteria: &[Convergence<T>],
+        max_iterations: usize,
+        mut observer: O,
+    ) -> Result<RootFinderResult<T>, RootsErr<E>>
+    where
+        F: FnMut(T) -> Result<(T, T), E>,
+        O: FnMut(&RootStep<T>)
+    {
+        let mut stepper = self.stepper(fun, x_left, x_right)?;
+        run(&mut stepper, criteria, max_iterations, Some(&mut observer))
+    }
+
+    /// Call solvers till any of convergence criteria is met,
+    /// return result and history of iterations
+    pub fn find_traced<F>(
+        &self,
+        fun: F,
+        x_left: T,
+        x_right: T,
+        criteria: &[Convergence<T>],
+        max_iterations: usize,
+    ) -> Result<RootTrace<T>, RootsErr<E>>
+    where
+        F: FnMut(T) -> Result<(T, T), E>
+    {
+        let mut history = Vec::with_capacity(max_iterations);
+        let res = self.find_observed(fun, x_left, x_right, criteria, max_iterations,
+            |step| history.push(*step))?;
+        Ok((res, history))
+    }
+}
+
+/// Iterate till any of convergence criteria is met or `max_iterations` is exhausted
+pub(super) fn run<R, E, T>(
+    stepper: &mut R,
+    criteria: &[Convergence<T>],
+    max_iterations: usize,
+    mut observer: Option<&mut Observer<'_, T>>,
+) -> Result<RootFinderResult<T>, RootsErr<E>>
+where
+    R: RootIterate<E, T>,
+    T: Float
+{
+    if !criteria.iter().all(Convergence::is_valid) {
+        return Err(RootsErr::BadTolerance);
+    }
+
+    stepper.eval_root(observer.is_some() || criteria.iter().any(Convergence::needs_residual));
+
+    let mut nr_iterations: usize = 0;
+    // no previous estimate yet, first solver step may return the initial guess
+    let mut old_root = T::nan();
+    let mut residual = T::nan();
+    let mut converged_by = None;
+
+    while nr_iterations < max_iterations {
+        let step = stepper.iterate()?;
+        if let Some(observer) = observer.as_mut() {
+            observer(&step);
+        }
+        residual = step.f_root;
+        converged_by = convergence::first_met(criteria, step.root, old_root, step.range, residual);
+        if converged_by.is_some() {
+            break;
+        }
+        old_root = step.root;
+        nr_iterations += 1;
+    }
+
+    let root = stepper.root();
+
+    if residual.is_nan() {
+        residual = stepper.eval(root)?;
+    }
+
+    Ok(RootFinderResult {
+        root,
+        nr_iterations,
+        converged_by,
+        range: stepper.range(),
+        residual,
+        max_iterations_reached: converged_by.is_none(),
+    })
+}
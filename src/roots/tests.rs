@@ -1,5 +1,7 @@
 use super::*;
 
+type TestFn = fn(f64) -> Result<f64, ()>;
+
 #[inline] fn sin(x: f64) -> Result<f64, ()> {
     Ok(x.sin())
 }
@@ -34,9 +36,12 @@ fn print_res(
         msg, x1, x2, res.root, expect, res.nr_iterations, max_iters, eps);
 }
 
-fn test_bisection(
+#[allow(clippy::too_many_arguments)]
+fn test_finder<S: RootFinderState>(
+    name: &str,
+    finder: RootFinder<S>,
     msg: &str,
-    fun: FnWithRoots,
+    fun: TestFn,
     x1: f64,
     x2: f64,
     max_iters: usize,
@@ -44,29 +49,22 @@ fn test_bisection(
     expect: f64,
     check_expected: bool)
 {
-    use super::bisection::*;
-
-    let finder = new_bisection_finder();
+    let res = finder.find(fun, x1, x2, eps, max_iters)
+        .expect("root finder failed");
 
-    let res = finder.find(fun, x1, x2, eps, max_iters);
-
-    if let Ok(res) = res {
-        print!("Bisection ");
-        print_res(msg, x1, x2, max_iters, eps, expect, &res);
-        if check_expected {
-            assert!(res.nr_iterations < max_iters);
-            // assert_f64_near!(res.root, expect, 4);
-            assert_float_absolute_eq!(res.root, expect, eps);
-        }
-    }
-    else {
-        assert!(false, "root finder failed");
+    print!("{:11}", name);
+    print_res(msg, x1, x2, max_iters, eps, expect, &res);
+    if check_expected {
+        assert!(res.nr_iterations < max_iters);
+        // assert_f64_near!(res.root, expect, 4);
+        assert_float_absolute_eq!(res.root, expect, eps);
     }
 }
 
-fn test_itp(
+#[allow(clippy::too_many_arguments)]
+fn test_solvers(
     msg: &str,
-    fun: FnWithRoots,
+    fun: TestFn,
     x1: f64,
     x2: f64,
     max_iters: usize,
@@ -74,29 +72,22 @@ fn test_itp(
     expect: f64,
     check_expected: bool)
 {
-    use super::itp::*;
-
-    let finder = new_itp_finder();
-
-    let res = finder.find(fun, x1, x2, eps, max_iters);
+    use super::bisection::new_bisection_finder;
+    use super::itp::new_itp_finder;
+    use super::brent::new_brent_finder;
+    use super::falsepos::{new_illinois_finder, new_anderson_bjorck_finder};
 
-    if let Ok(res) = res {
-        print!("ITP       ");
-        print_res(msg, x1, x2, max_iters, eps, expect, &res);
-        if check_expected {
-            assert!(res.nr_iterations < max_iters);
-            //assert_f64_near!(res.root, expect, 4);
-            assert_float_absolute_eq!(res.root, expect, eps);
-        }
-    }
-    else {
-        assert!(false, "root finder failed");
-    }
+    test_finder("Bisection", new_bisection_finder(), msg, fun, x1, x2, max_iters, eps, expect, check_expected);
+    test_finder("ITP",       new_itp_finder(),       msg, fun, x1, x2, max_iters, eps, expect, check_expected);
+    test_finder("Brent",     new_brent_finder(),     msg, fun, x1, x2, max_iters, eps, expect, check_expected);
+    test_finder("Illinois",  new_illinois_finder(),  msg, fun, x1, x2, max_iters, eps, expect, check_expected);
+    test_finder("A-Bjorck",  new_anderson_bjorck_finder(), msg, fun, x1, x2, max_iters, eps, expect, check_expected);
 }
 
-fn test_solvers(
+#[allow(clippy::too_many_arguments)]
+fn test_open_solvers(
     msg: &str,
-    fun: FnWithRoots,
+    fun: TestFn,
     x1: f64,
     x2: f64,
     max_iters: usize,
@@ -104,13 +95,21 @@ fn test_solvers(
     expect: f64,
     check_expected: bool)
 {
-    test_bisection(msg, fun, x1, x2, max_iters, eps, expect, check_expected);
-    test_itp      (msg, fun, x1, x2, max_iters, eps, expect, check_expected);
+    use super::secant::new_secant_finder;
+    use super::steffensen::new_steffensen_finder;
+
+    test_finder("Secant",     new_secant_finder(),     msg, fun, x1, x2, max_iters, eps, expect, check_expected);
+    test_finder("Steffensen", new_steffensen_finder(), msg, fun, x1, x2, max_iters, eps, expect, check_expected);
 }
 
 
 #[test]
 fn solvers() {
+    use super::bisection::new_bisection_finder;
+    use super::itp::new_itp_finder;
+    use super::brent::new_brent_finder;
+    use super::falsepos::{new_illinois_finder, new_anderson_bjorck_finder};
+
     // https://github.com/ampl/gsl/blob/master/roots/test.c
     test_solvers("f=sin[3,4]",       sin,  3.0,     4.0, 100, 1.0e-15, std::f64::consts::PI, true);
     test_solvers("f=sin[-4,-3]",     sin, -4.0,    -3.0, 100, 1.0e-15, -std::f64::consts::PI, true);
@@ -119,13 +118,409 @@ fn solvers() {
     test_solvers("f=cos[-3,0]",      cos, -3.0,     0.0, 100, 1.0e-15, -std::f64::consts::PI / 2.0, true);
     // https://github.com/ampl/gsl/blob/master/roots/test_funcs.c
     test_solvers("f=x^20 - 1 [0.1,2]",  |x| Ok(x.powi(20) - 1.0),  0.1, 2.0, 100, 1.0e-15, 1.0, true);
-    //test_solvers("f=(x-1)^7 [0.9995, 1.0002]",  |x| Ok((x - 1.0).powi(7)),  0.9995, 1.0002, 100, 1.0e-7, 1.0, true);// ITP problem FIXME
     test_solvers("f=x*exp(-x) [-1/3,2]",  |x| Ok(x*(-x).exp()),  -1.0/3.0, 2.0, 100, 1.0e-15, 0.0, true);
     test_solvers("f=x^2 - 1e-8 [0,1]",  |x| Ok(x*x - 1.0e-8),  0.0, 1.0, 100, 1.0e-15, (1.0e-8f64).sqrt(), true);
     //"sqrt(|x|)*sgn(x)", -1.0 / 3.0, 1.0, 0.0);
 
     // https://github.com/paulnorthrop/itp
     test_solvers("f=lambert",     lambert, -1.0, 1.0, 100, 1.0e-7, 0.5671, false);
-    test_solvers("f=staircase", staircase, -1.0, 1.0, 100, 1.0e-14, 7.4e-11, false);
+    test_solvers("f=staircase", staircase, -1.0, 1.0, 100, 1.0e-14, 0.0, true);
     test_solvers("f=warsaw",       warsaw, -1.0, 1.0, 100, 1.0e-7, -0.6817, false);
-}
\ No newline at end of file
+
+    // flat function, Delta criterion stops false position methods a few epsilon from the root,
+    // ITP bound is checked in itp_worst_case
+    fn flat<S: RootFinderState>(name: &str, finder: RootFinder<S>, tolerance: f64) {
+        let (x1, x2, max_iters, eps) = (0.9995, 1.0002, 100, 1.0e-7);
+        let res = finder.find(|x| Ok((x - 1.0).powi(7)), x1, x2, eps, max_iters)
+            .expect("root finder failed");
+
+        print!("{:11}", name);
+        print_res("f=(x-1)^7", x1, x2, max_iters, eps, 1.0, &res);
+        assert!(res.nr_iterations < max_iters);
+        assert_float_absolute_eq!(res.root, 1.0, tolerance);
+    }
+
+    flat("Bisection", new_bisection_finder(), 1.0e-7);
+    flat("ITP",       new_itp_finder(),       1.0e-7);
+    flat("Brent",     new_brent_finder(),     1.0e-7);
+    flat("Illinois",  new_illinois_finder(),  1.0e-6);
+    flat("A-Bjorck",  new_anderson_bjorck_finder(), 1.0e-6);
+}
+
+#[test]
+fn itp_worst_case() {
+    use super::itp::new_itp_finder_with;
+
+    // https://github.com/paulnorthrop/itp
+    // ITP needs at most n_max iterations to get the middle of the range within epsilon
+    let cases: [(&str, TestFn, f64, f64, f64); 5] = [
+        ("f=sin[3,4]",   sin,      3.0,    4.0,    std::f64::consts::PI),
+        ("f=(x-1)^7",    |x| Ok((x - 1.0).powi(7)), 0.9995, 1.0002, 1.0),
+        ("f=lambert",    lambert, -1.0,    1.0,    0.5671432904097838),
+        ("f=staircase",  staircase, -1.0,  1.0,    0.0),
+        ("f=warsaw",     warsaw,  -1.0,    1.0,    1.0 / std::f64::consts::PI - 1.0),
+    ];
+
+    for (msg, fun, x1, x2, expect) in cases {
+        for (k1, k2, n0, eps) in [(0.1, 2.0, 1, 1.0e-7), (0.2, 2.0, 0, 1.0e-10), (0.05, 1.5, 2, 1.0e-12)] {
+            let finder = new_itp_finder_with(k1, k2, n0, eps);
+            let mut stepper = finder.stepper(fun, x1, x2).expect("root finder failed");
+            // allow rounding of the endpoints
+            let rounding = 4.0 * f64::EPSILON * x1.abs().max(x2.abs());
+            while stepper.range().1 - stepper.range().0 > 2.0 * eps + rounding {
+                stepper.iterate().expect("root finder failed");
+            }
+            let n_max = stepper.state().max_iterations();
+            println!("{:12} eps={} iterations:{} (n_max:{})", msg, eps, stepper.nr_iterations(), n_max);
+            assert!(stepper.nr_iterations() <= n_max);
+            let (a, b, _, _) = stepper.range();
+            assert_float_absolute_eq!((a + b) / 2.0, expect, eps);
+        }
+    }
+}
+
+#[test]
+fn open_solvers() {
+    // Secant and Steffensen do not keep bracket, use good initial ranges
+    test_open_solvers("f=sin[3,4]",       sin,  3.0,     4.0, 100, 1.0e-15, std::f64::consts::PI, true);
+    test_open_solvers("f=sin[-4,-3]",     sin, -4.0,    -3.0, 100, 1.0e-15, -std::f64::consts::PI, true);
+    test_open_solvers("f=sin[-1/3,1]",    sin, -1.0/3.0, 1.0, 100, 1.0e-15, 0.0, true);
+    test_open_solvers("f=cos[0,3]",       cos,  0.0,     3.0, 100, 1.0e-15, std::f64::consts::PI / 2.0, true);
+    test_open_solvers("f=cos[-3,0]",      cos, -3.0,     0.0, 100, 1.0e-15, -std::f64::consts::PI / 2.0, true);
+    test_open_solvers("f=x^20 - 1 [0.99,1.02]", |x| Ok(x.powi(20) - 1.0), 0.99, 1.02, 100, 1.0e-15, 1.0, true);
+    test_open_solvers("f=lambert",     lambert, -1.0, 1.0, 100, 1.0e-7, 0.5671, false);
+}
+
+#[inline] fn sin_fdf(x: f64) -> Result<(f64, f64), ()> {
+    Ok((x.sin(), x.cos()))
+}
+
+#[inline] fn cos_fdf(x: f64) -> Result<(f64, f64), ()> {
+    Ok((x.cos(), -x.sin()))
+}
+
+#[allow(clippy::too_many_arguments)]
+fn test_deriv_finder<S: RootFinderState, F>(
+    name: &str,
+    finder: super::newton::DerivRootFinder<S>,
+    msg: &str,
+    fun: F,
+    x1: f64,
+    x2: f64,
+    max_iters: usize,
+    eps: f64,
+    expect: f64)
+where
+    F: FnMut(f64) -> Result<(f64, f64), ()>
+{
+    let res = finder.find(fun, x1, x2, eps, max_iters)
+        .expect("root finder failed");
+
+    print!("{:11}", name);
+    print_res(msg, x1, x2, max_iters, eps, expect, &res);
+    assert!(res.nr_iterations < max_iters);
+    assert_float_absolute_eq!(res.root, expect, eps);
+}
+
+fn test_deriv_solvers<F>(
+    msg: &str,
+    mut fun: F,
+    x1: f64,
+    x2: f64,
+    max_iters: usize,
+    eps: f64,
+    expect: f64)
+where
+    F: FnMut(f64) -> Result<(f64, f64), ()>
+{
+    use super::newton::{new_newton_finder, new_safe_newton_finder};
+
+    // unbracketed Newton starts from the middle of the range
+    let mid = (x1 + x2)/2.0;
+    test_deriv_finder("Newton",      new_newton_finder(),      msg, &mut fun, mid, mid, max_iters, eps, expect);
+    test_deriv_finder("SafeNewton",  new_safe_newton_finder(), msg, &mut fun, x1,  x2,  max_iters, eps, expect);
+}
+
+#[test]
+fn deriv_solvers() {
+    use super::newton::fn_and_derivative as fdf;
+
+    // https://github.com/ampl/gsl/blob/master/roots/test.c
+    test_deriv_solvers("f=sin[3,4]",    sin_fdf,  3.0,  4.0, 100, 1.0e-15, std::f64::consts::PI);
+    test_deriv_solvers("f=sin[-4,-3]",  sin_fdf, -4.0, -3.0, 100, 1.0e-15, -std::f64::consts::PI);
+    test_deriv_solvers("f=cos[0,3]",    cos_fdf,  0.0,  3.0, 100, 1.0e-15, std::f64::consts::PI / 2.0);
+    test_deriv_solvers("f=x^20 - 1 [0.1,2]",
+        fdf(|x: f64| Ok(x.powi(20) - 1.0), |x: f64| Ok(20.0 * x.powi(19))), 0.1, 2.0, 100, 1.0e-15, 1.0);
+    test_deriv_solvers("f=x^2 - 1e-8 [0,1]",
+        fdf(|x: f64| Ok(x*x - 1.0e-8), |x: f64| Ok(2.0 * x)), 0.0, 1.0, 100, 1.0e-15, (1.0e-8f64).sqrt());
+}
+
+#[test]
+fn closures() {
+    use super::brent::new_brent_finder;
+
+    // Function with runtime parameter that counts calls
+    let coeffs = vec![-2.0, 0.0, 1.0];
+    let mut nr_calls = 0;
+    let res = new_brent_finder().find(|x| {
+            nr_calls += 1;
+            Ok::<f64, ()>(crate::polynomial::polynomial_n(x, &coeffs))
+        }, 0.0, 2.0, 1.0e-15, 100)
+        .expect("root finder failed");
+    assert_float_absolute_eq!(res.root, 2.0f64.sqrt(), 1.0e-15);
+    assert!(nr_calls > 2);
+
+    // User error is returned to the caller
+    #[derive(Debug, PartialEq)]
+    struct OutOfDomain(f64);
+
+    let res = new_brent_finder().find(|x: f64| {
+            if x < 1.5 { Ok(x - 1.0) } else { Err(OutOfDomain(x)) }
+        }, 0.0, 2.0, 1.0e-15, 100);
+    match res {
+        Err(RootsErr::FunctionFailed(err)) => assert_eq!(err, OutOfDomain(2.0)),
+        _ => panic!("expected function failure"),
+    }
+}
+
+#[test]
+fn convergence_criteria() {
+    use super::bisection::new_bisection_finder;
+    use super::brent::new_brent_finder;
+    use super::newton::new_safe_newton_finder;
+
+    // Interval with relative tolerance
+    let criteria = [Convergence::Interval{epsabs: 0.0, epsrel: 1.0e-12}];
+    let res = new_bisection_finder().find_with(sin, 3.0, 4.0, &criteria, 100)
+        .expect("root finder failed");
+    assert_eq!(res.converged_by, Some(criteria[0]));
+    assert!(!res.max_iterations_reached);
+    assert!(res.range.0 <= std::f64::consts::PI && std::f64::consts::PI <= res.range.1);
+    assert!(res.range.1 - res.range.0 < 1.0e-12 * 3.0);
+
+    // Residual fires before interval
+    let criteria = [
+        Convergence::Interval{epsabs: 1.0e-15, epsrel: 0.0},
+        Convergence::Residual{epsabs: 1.0e-3},
+    ];
+    let res = new_bisection_finder().find_with(sin, 3.0, 4.0, &criteria, 100)
+        .expect("root finder failed");
+    assert_eq!(res.converged_by, Some(criteria[1]));
+    assert!(res.residual.abs() < 1.0e-3);
+    assert_float_absolute_eq!(res.residual, res.root.sin(), 0.0);
+
+    // Exhausted iterations are reported
+    let criteria = [Convergence::Delta{epsabs: 0.0, epsrel: 0.0}];
+    let res = new_bisection_finder().find_with(lambert, -1.0, 1.0, &criteria, 10)
+        .expect("root finder failed");
+    assert!(res.max_iterations_reached);
+    assert_eq!(res.converged_by, None);
+    assert_eq!(res.nr_iterations, 10);
+
+    let res = new_brent_finder().find_with(lambert, -1.0, 1.0,
+        &[Convergence::Residual{epsabs: -1.0}], 10);
+    assert!(matches!(res, Err(RootsErr::BadTolerance)));
+
+    // Derivative solvers share the criteria
+    let criteria = [Convergence::Interval{epsabs: 1.0e-14, epsrel: 0.0}];
+    let res = new_safe_newton_finder().find_with(cos_fdf, 0.0, 3.0, &criteria, 100)
+        .expect("root finder failed");
+    assert_eq!(res.converged_by, Some(criteria[0]));
+    assert_float_absolute_eq!(res.root, std::f64::consts::PI / 2.0, 1.0e-14);
+}
+
+#[test]
+fn convergence_history() {
+    use super::bisection::new_bisection_finder;
+    use super::itp::new_itp_finder;
+
+    let criteria = [Convergence::Interval{epsabs: 1.0e-12, epsrel: 0.0}];
+
+    let (bisection_res, bisection_history) = new_bisection_finder()
+        .find_traced(lambert, -1.0, 1.0, &criteria, 100)
+        .expect("root finder failed");
+    let (itp_res, itp_history) = new_itp_finder()
+        .find_traced(lambert, -1.0, 1.0, &criteria, 100)
+        .expect("root finder failed");
+
+    // cargo test --lib convergence_history -- --nocapture
+    for step in &itp_history {
+        println!("ITP {:3} x={:.15} f(x)={:+.3e} width={:.3e}",
+            step.iteration, step.root, step.f_root, step.range.1 - step.range.0);
+    }
+
+    assert_eq!(bisection_history.len(), bisection_res.nr_iterations + 1);
+    assert_eq!(itp_history.len(), itp_res.nr_iterations + 1);
+    assert!(itp_history.len() < bisection_history.len());
+
+    // Bisection halves the range each iteration
+    for steps in bisection_history.windows(2) {
+        let (w0, w1) = (steps[0].range.1 - steps[0].range.0, steps[1].range.1 - steps[1].range.0);
+        assert_float_relative_eq!(w1, w0 / 2.0, 1.0e-9);
+    }
+
+    for history in [&bisection_history, &itp_history] {
+        for (i, step) in history.iter().enumerate() {
+            assert_eq!(step.iteration, i + 1);
+            assert!(step.range.0 <= step.root && step.root <= step.range.1);
+            assert_eq!(step.f_root, lambert(step.root).unwrap());
+        }
+    }
+}
+
+#[test]
+fn stepper() {
+    use super::brent::new_brent_finder;
+    use super::newton::new_safe_newton_finder;
+
+    let mut stepper = new_brent_finder().stepper(cos, 0.0, 3.0)
+        .expect("root finder failed");
+    assert_eq!(stepper.nr_iterations(), 0);
+    assert_eq!(stepper.range(), (0.0, 3.0, 1.0, 3.0f64.cos()));
+
+    let step = stepper.iterate().expect("iteration failed");
+    assert_eq!(step.iteration, 1);
+    assert_eq!(step.root, stepper.root());
+    assert_eq!(step.range, stepper.range());
+
+    let last = stepper.take(20).last().unwrap().expect("iteration failed");
+    assert_eq!(last.iteration, 21);
+    assert_float_absolute_eq!(last.root, std::f64::consts::PI / 2.0, 1.0e-15);
+
+    // Iterator stops after function failure
+    let mut nr_calls = 0;
+    let steps: Vec<_> = new_brent_finder().stepper(|x: f64| {
+            nr_calls += 1;
+            if nr_calls < 5 { Ok(x.cos()) } else { Err(nr_calls) }
+        }, 0.0, 3.0)
+        .expect("root finder failed")
+        .take(10)
+        .collect();
+    assert!(steps.len() < 10);
+    assert!(matches!(steps.last(), Some(Err(RootsErr::FunctionFailed(_)))));
+
+    // derivative solvers step the same way
+    let mut stepper = new_safe_newton_finder().stepper(cos_fdf, 0.0, 3.0)
+        .expect("root finder failed");
+    assert_eq!(stepper.root(), 1.5);
+    let step = stepper.iterate().expect("iteration failed");
+    assert_eq!(step.iteration, 1);
+    assert_eq!(step.range, stepper.range());
+    assert_eq!(step.f_root, step.root.cos());
+
+    let (res, history) = new_safe_newton_finder().find_traced(cos_fdf, 0.0, 3.0,
+        &[Convergence::Residual{epsabs: 1.0e-15}], 50)
+        .expect("root finder failed");
+    assert_eq!(history.len(), res.nr_iterations + 1);
+    for (i, step) in history.iter().enumerate() {
+        assert_eq!(step.iteration, i + 1);
+        assert!(step.range.0 <= step.root && step.root <= step.range.1);
+        assert_eq!(step.f_root, step.root.cos());
+    }
+    assert_float_absolute_eq!(res.root, std::f64::consts::PI / 2.0, 1.0e-15);
+}
+
+#[test]
+fn bracket_scan() {
+    use super::bracket::{expand_bracket, scan_brackets};
+    use super::brent::new_brent_finder;
+    use std::f64::consts::PI;
+
+    let (x_left, x_right, f_left, f_right) = expand_bracket(cos, 10.1, 0.1, 50)
+        .expect("bracket not found");
+    assert!(f_left * f_right <= 0.0);
+    let res = new_brent_finder().find(cos, x_left, x_right, 1.0e-14, 50)
+        .expect("root finder failed");
+    assert_float_absolute_eq!(res.root.cos(), 0.0, 1.0e-14);
+
+    assert!(matches!(expand_bracket(|x: f64| Ok::<f64, ()>(x*x + 1.0), 0.0, 1.0, 20),
+        Err(RootsErr::BracketNotFound)));
+
+    // exact zero on a sample point is reported once
+    let brackets = scan_brackets(|x: f64| Ok::<f64, ()>(x), -1.0, 1.0, 4)
+        .expect("scan failed");
+    assert_eq!(brackets, vec![(0.0, 0.0, 0.0, 0.0)]);
+
+    let criteria = [Convergence::Interval{epsabs: 1.0e-14, epsrel: 0.0}];
+
+    // x=-1 simple, x=1 triple, x=2 double that does not change sign
+    let poly = |x: f64| Ok::<f64, ()>((x + 1.0) * (x - 1.0).powi(3) * (x - 2.0).powi(2));
+    let roots = new_brent_finder().find_all(poly, -3.0, 3.0, 50, &criteria, 100)
+        .expect("root finder failed");
+    assert_eq!(roots.len(), 2);
+    assert_float_absolute_eq!(roots[0].root, -1.0, 1.0e-13);
+    assert_eq!(roots[0].multiplicity, 1);
+    assert_float_absolute_eq!(roots[1].root, 1.0, 1.0e-4);
+    assert_eq!(roots[1].multiplicity, 3);
+
+    // poles of tan(x) change sign but are not roots
+    let roots = new_brent_finder().find_all(|x: f64| Ok::<f64, ()>(x.tan()), 2.0, 7.0, 100,
+        &criteria, 100).expect("root finder failed");
+    assert_eq!(roots.len(), 2);
+    assert_float_absolute_eq!(roots[0].root, PI, 1.0e-14);
+    assert_float_absolute_eq!(roots[1].root, 2.0 * PI, 1.0e-14);
+    assert!(roots.iter().all(|r| r.multiplicity == 1));
+
+    // closely spaced roots of sin(20x) in [0, 1]
+    let roots = new_brent_finder().find_all(|x: f64| Ok::<f64, ()>((20.0 * x).sin()), 0.01, 1.0, 200,
+        &criteria, 100).expect("root finder failed");
+    assert_eq!(roots.len(), 6);
+    for (i, r) in roots.iter().enumerate() {
+        assert_float_absolute_eq!(r.root, (i + 1) as f64 * PI / 20.0, 1.0e-14);
+    }
+}
+
+#[test]
+fn single_precision() {
+    use super::bisection::new_bisection_finder;
+    use super::itp::new_itp_finder;
+    use super::brent::new_brent_finder;
+    use super::falsepos::new_illinois_finder;
+    use super::secant::new_secant_finder;
+    use super::newton::{new_newton_finder, new_safe_newton_finder};
+    use super::bracket::expand_bracket;
+    use std::f32::consts::PI;
+
+    let sin = |x: f32| Ok::<f32, ()>(x.sin());
+    let criteria = [Convergence::Interval{epsabs: 4.0 * f32::EPSILON, epsrel: 0.0}];
+
+    let res = new_bisection_finder().find_with(sin, 3.0, 4.0, &criteria, 100)
+        .expect("root finder failed");
+    assert!(res.nr_iterations < 30);
+    assert_float_absolute_eq!(res.root, PI, 4.0 * f32::EPSILON);
+
+    let res = new_itp_finder().find_with(sin, 3.0, 4.0, &criteria, 100)
+        .expect("root finder failed");
+    assert!(res.nr_iterations < 30);
+    assert_float_absolute_eq!(res.root, PI, 4.0 * f32::EPSILON);
+
+    let res = new_brent_finder().find_with(sin, 3.0, 4.0, &criteria, 100)
+        .expect("root finder failed");
+    assert!(res.nr_iterations < 10);
+    assert_float_absolute_eq!(res.root, PI, 4.0 * f32::EPSILON);
+
+    let res = new_illinois_finder().find(sin, 3.0f32, 4.0, f32::EPSILON, 100)
+        .expect("root finder failed");
+    assert_float_absolute_eq!(res.root, PI, 4.0 * f32::EPSILON);
+
+    let res = new_secant_finder().find(sin, 3.0f32, 4.0, f32::EPSILON, 100)
+        .expect("root finder failed");
+    assert_float_absolute_eq!(res.root, PI, 4.0 * f32::EPSILON);
+
+    let sin_fdf = |x: f32| Ok::<(f32, f32), ()>((x.sin(), x.cos()));
+    let res = new_newton_finder().find(sin_fdf, 3.0f32, 3.0, f32::EPSILON, 100)
+        .expect("root finder failed");
+    assert_float_absolute_eq!(res.root, PI, 4.0 * f32::EPSILON);
+    let res = new_safe_newton_finder().find(sin_fdf, 3.0f32, 4.0, f32::EPSILON, 100)
+        .expect("root finder failed");
+    assert_float_absolute_eq!(res.root, PI, 4.0 * f32::EPSILON);
+
+    let (x_left, x_right, _, _) = expand_bracket(sin, 2.5f32, 0.1, 50)
+        .expect("bracket not found");
+    assert!(x_left < PI && PI < x_right);
+
+    let roots = new_brent_finder().find_all(sin, -4.0f32, 4.0, 40, &criteria, 100)
+        .expect("root finder failed");
+    assert_eq!(roots.len(), 3);
+    assert_float_absolute_eq!(roots[0].root, -PI, 4.0 * f32::EPSILON);
+}